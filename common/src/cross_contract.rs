@@ -1,16 +1,41 @@
 multiversx_sc::imports!();
 
-use crate::structs::JobData;
+use crate::structs::{AgentDetails, AgentStatus, EscrowData, JobData, ValidationRequestData};
 
 /// Cross-contract storage reads shared across contracts.
 #[multiversx_sc::module]
 pub trait CrossContractModule {
-    /// Read agent owner from identity-registry's `agents` BiDiMapper.
-    #[storage_mapper_from_address("agents")]
-    fn external_agents(
+    /// Read agent owner from identity-registry's `agentOwner` storage. Empty for unknown agents.
+    #[storage_mapper_from_address("agentOwner")]
+    fn external_agent_owner(
         &self,
         address: ManagedAddress,
-    ) -> BiDiMapper<u64, ManagedAddress, ManagedAddress<Self::Api>>;
+        nonce: u64,
+    ) -> SingleValueMapper<ManagedAddress, ManagedAddress>;
+
+    /// Read agent details (name, public key) from identity-registry's `agentDetails` storage.
+    #[storage_mapper_from_address("agentDetails")]
+    fn external_agent_details(
+        &self,
+        address: ManagedAddress,
+        nonce: u64,
+    ) -> SingleValueMapper<AgentDetails<Self::Api>, ManagedAddress>;
+
+    /// Read agent status from identity-registry's `agentStatus` storage. Empty reads as `Active`.
+    #[storage_mapper_from_address("agentStatus")]
+    fn external_agent_status(
+        &self,
+        address: ManagedAddress,
+        nonce: u64,
+    ) -> SingleValueMapper<AgentStatus, ManagedAddress>;
+
+    /// Read delegated operators (address -> permission bitmask) from identity-registry's `agentOperators` storage.
+    #[storage_mapper_from_address("agentOperators")]
+    fn external_agent_operators(
+        &self,
+        address: ManagedAddress,
+        nonce: u64,
+    ) -> MapMapper<ManagedAddress, u8, ManagedAddress>;
 
     /// Read job data from validation-registry's `jobData` storage.
     #[storage_mapper_from_address("jobData")]
@@ -20,18 +45,59 @@ pub trait CrossContractModule {
         job_id: &ManagedBuffer,
     ) -> SingleValueMapper<JobData<Self::Api>, ManagedAddress>;
 
-    /// Read agent service config from identity-registry's `agentServiceConfigs` storage.
+    /// Read a validation request from validation-registry's `validationRequestData` storage.
+    #[storage_mapper_from_address("validationRequestData")]
+    fn external_validation_request_data(
+        &self,
+        address: ManagedAddress,
+        request_hash: &ManagedBuffer,
+    ) -> SingleValueMapper<ValidationRequestData<Self::Api>, ManagedAddress>;
+
+    /// Read whether a job has milestones enabled from validation-registry's `jobMilestones` storage.
+    #[storage_mapper_from_address("jobMilestones")]
+    fn external_job_milestones(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<bool, ManagedAddress>;
+
+    /// Read whether a validation request passed from validation-registry's `requestPassed` storage.
+    #[storage_mapper_from_address("requestPassed")]
+    fn external_request_passed(
+        &self,
+        address: ManagedAddress,
+        request_hash: &ManagedBuffer,
+    ) -> SingleValueMapper<bool, ManagedAddress>;
+
+    /// Read an escrow from escrow's `escrowData` storage. Empty when nothing was deposited for the job.
+    #[storage_mapper_from_address("escrowData")]
+    fn external_escrow_data(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<EscrowData<Self::Api>, ManagedAddress>;
+
+    /// Read the accepted payments per service from identity-registry's `agentServiceConfigs` storage.
     #[storage_mapper_from_address("agentServiceConfigs")]
     fn external_agent_service_config(
         &self,
         address: ManagedAddress,
         nonce: u64,
-    ) -> MapMapper<u32, Payment<Self::Api>, ManagedAddress<Self::Api>>;
+    ) -> MapMapper<u32, ManagedVec<Payment<Self::Api>>, ManagedAddress<Self::Api>>;
+
+    /// Read the pay-on-verification services from identity-registry's `agentPayOnVerificationServices` storage.
+    #[storage_mapper_from_address("agentPayOnVerificationServices")]
+    fn external_agent_pay_on_verification_services(
+        &self,
+        address: ManagedAddress,
+        nonce: u64,
+    ) -> UnorderedSetMapper<u32, ManagedAddress>;
 
-    /// Read agent token ID from identity-registry's NonFungibleTokenMapper.
-    #[storage_mapper_from_address("agentTokenId")]
-    fn external_agent_token_id(
+    /// Read per-service pass thresholds from identity-registry's `agentServiceThresholds` storage.
+    #[storage_mapper_from_address("agentServiceThresholds")]
+    fn external_agent_service_thresholds(
         &self,
         address: ManagedAddress,
-    ) -> SingleValueMapper<TokenIdentifier, ManagedAddress<Self::Api>>;
+        nonce: u64,
+    ) -> MapMapper<u32, u8, ManagedAddress>;
 }
@@ -1,3 +1,10 @@
 pub const ERR_JOB_NOT_FOUND: &str = "Job not found";
 pub const ERR_NOT_AGENT_OWNER: &str = "Only the agent owner can perform this action";
 pub const ERR_AGENT_NOT_FOUND: &str = "Agent not found";
+pub const ERR_AGENT_PAUSED: &str = "Agent is paused";
+pub const ERR_INVALID_THRESHOLD: &str =
+    "Pass threshold must not exceed the maximum validation score";
+pub const ERR_FEE_TOO_HIGH: &str = "Protocol fee exceeds the maximum";
+pub const ERR_TREASURY_NOT_SET: &str = "Treasury address is not set";
+pub const ERR_NOT_FEE_CLAIMER: &str = "Only the owner or the treasury can claim fees";
+pub const ERR_NOTHING_TO_CLAIM: &str = "No fees to claim";
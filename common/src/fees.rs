@@ -0,0 +1,126 @@
+multiversx_sc::imports!();
+
+use crate::errors::{
+    ERR_FEE_TOO_HIGH, ERR_NOT_FEE_CLAIMER, ERR_NOTHING_TO_CLAIM, ERR_TREASURY_NOT_SET,
+};
+use crate::structs::FeesClaimedEventData;
+
+/// Basis points in 100%.
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// Highest protocol fee the owner can set (10%).
+pub const MAX_PROTOCOL_FEE_BPS: u32 = 1_000;
+
+/// Protocol fee taken on payouts to agents, accumulated per token until claimed to the treasury.
+/// Funds are charged the rate recorded when they came in, so a fee change only affects
+/// deposits made afterwards.
+#[multiversx_sc::module]
+pub trait FeesModule {
+    #[only_owner]
+    #[endpoint(set_protocol_fee)]
+    fn set_protocol_fee(&self, fee_bps: u32) {
+        require!(fee_bps <= MAX_PROTOCOL_FEE_BPS, ERR_FEE_TOO_HIGH);
+        self.protocol_fee_bps().set(fee_bps);
+    }
+
+    #[only_owner]
+    #[endpoint(set_treasury)]
+    fn set_treasury(&self, treasury: ManagedAddress) {
+        self.treasury().set(&treasury);
+    }
+
+    /// Send every accumulated fee to the treasury. Callable by the owner or the treasury.
+    #[endpoint(claim_fees)]
+    fn claim_fees(&self) {
+        require!(!self.treasury().is_empty(), ERR_TREASURY_NOT_SET);
+        let treasury = self.treasury().get();
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == treasury || caller == self.blockchain().get_owner_address(),
+            ERR_NOT_FEE_CLAIMER
+        );
+
+        let payments = self.accumulated_fee_payments();
+        require!(!payments.is_empty(), ERR_NOTHING_TO_CLAIM);
+        self.accumulated_fees().clear();
+
+        self.tx().to(&treasury).payment(&payments).transfer();
+        self.fees_claimed_event(&treasury, FeesClaimedEventData { payments });
+    }
+
+    #[view(get_accumulated_fees)]
+    fn get_accumulated_fees(&self) -> MultiValueEncoded<Payment<Self::Api>> {
+        self.accumulated_fee_payments().into_iter().collect()
+    }
+
+    /// Keep the protocol fee at `fee_bps` out of `amount` and return what is left for the payee.
+    fn take_protocol_fee(
+        &self,
+        token_id: &TokenId<Self::Api>,
+        token_nonce: u64,
+        amount: &BigUint,
+        fee_bps: u32,
+    ) -> BigUint {
+        let fee = amount * fee_bps as u64 / BPS_DENOMINATOR;
+        if fee == 0u64 {
+            return amount.clone();
+        }
+
+        let key = (token_id.clone(), token_nonce);
+        let total = self.accumulated_fees().get(&key).unwrap_or_default() + &fee;
+        self.accumulated_fees().insert(key, total);
+        amount - &fee
+    }
+
+    /// `take_protocol_fee` over each payment of a multi-transfer.
+    fn take_protocol_fees(
+        &self,
+        payments: &ManagedVec<Payment<Self::Api>>,
+        fee_bps: u32,
+    ) -> ManagedVec<Payment<Self::Api>> {
+        let mut net = ManagedVec::new();
+        for payment in payments.iter() {
+            let amount = self.take_protocol_fee(
+                &payment.token_identifier,
+                payment.token_nonce,
+                payment.amount.as_big_uint(),
+                fee_bps,
+            );
+            if let Some(amount) = NonZeroBigUint::new(amount) {
+                net.push(Payment::new(
+                    payment.token_identifier.clone(),
+                    payment.token_nonce,
+                    amount,
+                ));
+            }
+        }
+        net
+    }
+
+    fn accumulated_fee_payments(&self) -> ManagedVec<Payment<Self::Api>> {
+        let mut payments = ManagedVec::new();
+        for ((token_id, token_nonce), amount) in self.accumulated_fees().iter() {
+            if let Some(amount) = NonZeroBigUint::new(amount) {
+                payments.push(Payment::new(token_id, token_nonce, amount));
+            }
+        }
+        payments
+    }
+
+    #[event("feesClaimed")]
+    fn fees_claimed_event(
+        &self,
+        #[indexed] treasury: &ManagedAddress,
+        data: FeesClaimedEventData<Self::Api>,
+    );
+
+    #[view(get_protocol_fee_bps)]
+    #[storage_mapper("protocolFeeBps")]
+    fn protocol_fee_bps(&self) -> SingleValueMapper<u32>;
+
+    #[view(get_treasury)]
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("accumulatedFees")]
+    fn accumulated_fees(&self) -> MapMapper<(TokenId<Self::Api>, u64), BigUint>;
+}
@@ -2,6 +2,7 @@
 
 pub mod cross_contract;
 pub mod errors;
+pub mod fees;
 pub mod structs;
 
 pub use structs::*;
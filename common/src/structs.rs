@@ -3,13 +3,18 @@ multiversx_sc::derive_imports!();
 
 // ── Job types (used by validation-registry and reputation-registry) ──
 
+/// Variants are appended only, so stored discriminants keep their meaning across upgrades.
+/// Allowed transitions are enforced by validation-registry.
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
 pub enum JobStatus {
     New,
     Pending,
     Verified,
     ValidationRequested,
+    Rejected,
+    Cancelled,
+    Expired,
 }
 
 #[type_abi]
@@ -24,6 +29,9 @@ pub struct JobData<M: ManagedTypeApi> {
 
 // ── Validation types (ERC-8004 validationRequest/Response) ──
 
+/// Validator responses and pass thresholds are scores in `0..=MAX_VALIDATION_SCORE`.
+pub const MAX_VALIDATION_SCORE: u8 = 100;
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
 pub struct ValidationRequestData<M: ManagedTypeApi> {
@@ -34,10 +42,111 @@ pub struct ValidationRequestData<M: ManagedTypeApi> {
     pub response_hash: ManagedBuffer<M>,
     pub tag: ManagedBuffer<M>,
     pub last_update: TimestampSeconds,
+    pub request_uri: ManagedBuffer<M>,
+    pub response_uri: ManagedBuffer<M>,
+}
+
+// ── Escrow types (used by escrow, read by validation-registry) ──
+
+/// Escrow settlement status.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum EscrowStatus {
+    Active,
+    Released,
+    Refunded,
+    Disputed,
+    Resolved,
+}
+
+/// Settlement status of a single milestone.
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Debug,
+)]
+pub enum MilestoneStatus {
+    Pending,
+    Released,
+    Refunded,
+    /// Settled by an arbiter's split of the disputed escrow, not per milestone.
+    Resolved,
+}
+
+/// Part of an escrow released on its own once the validation request with hash
+/// `deliverable_hash` passes for the escrow's job.
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug,
+)]
+pub struct Milestone<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub deliverable_hash: ManagedBuffer<M>,
+    pub status: MilestoneStatus,
+}
+
+/// On-chain escrow record. `milestones` is empty for lump-sum escrows; otherwise its
+/// amounts add up to `amount`. `protocol_fee_bps` is the fee rate at deposit time, charged
+/// on every payout to the receiver. `agent_nonce` and `job_creation_timestamp` pin the job
+/// the escrow was funded against, since a cleaned job id can be initialised again.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct EscrowData<M: ManagedTypeApi> {
+    pub employer: ManagedAddress<M>,
+    pub receiver: ManagedAddress<M>,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+    pub poa_hash: ManagedBuffer<M>,
+    pub deadline: TimestampSeconds,
+    pub status: EscrowStatus,
+    pub milestones: ManagedVec<M, Milestone<M>>,
+    pub protocol_fee_bps: u32,
+    pub agent_nonce: u64,
+    pub job_creation_timestamp: TimestampMillis,
+}
+
+impl<M: ManagedTypeApi> EscrowData<M> {
+    /// Funds still locked: the whole amount of an active lump-sum escrow, or the
+    /// pending milestones of a milestone escrow.
+    pub fn remaining(&self) -> BigUint<M> {
+        if !matches!(self.status, EscrowStatus::Active | EscrowStatus::Disputed) {
+            return BigUint::zero();
+        }
+        if self.milestones.is_empty() {
+            return self.amount.clone();
+        }
+
+        let mut remaining = BigUint::zero();
+        for milestone in self.milestones.iter() {
+            if milestone.status == MilestoneStatus::Pending {
+                remaining += &milestone.amount;
+            }
+        }
+        remaining
+    }
 }
 
 // ── Agent types (used by identity-registry) ──
 
+// ── Operator permissions (bitmask granted per agent in identity-registry) ──
+
+pub const PERMISSION_SUBMIT_PROOF: u8 = 1 << 0;
+pub const PERMISSION_REQUEST_VALIDATION: u8 = 1 << 1;
+pub const PERMISSION_EDIT_METADATA: u8 = 1 << 2;
+pub const PERMISSION_EDIT_SERVICES: u8 = 1 << 3;
+pub const ALL_PERMISSIONS: u8 = PERMISSION_SUBMIT_PROOF
+    | PERMISSION_REQUEST_VALIDATION
+    | PERMISSION_EDIT_METADATA
+    | PERMISSION_EDIT_SERVICES;
+
+/// Empty storage decodes as `Active`, so agents registered before statuses existed stay active.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum AgentStatus {
+    Active,
+    Paused,
+}
+
 #[type_abi]
 #[derive(
     TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, PartialEq, Debug,
@@ -75,3 +184,9 @@ pub struct AgentRegisteredEventData<M: ManagedTypeApi> {
     pub name: ManagedBuffer<M>,
     pub uri: ManagedBuffer<M>,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FeesClaimedEventData<M: ManagedTypeApi> {
+    pub payments: ManagedVec<M, Payment<M>>,
+}
@@ -0,0 +1,23 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait ConfigModule: crate::storage::StorageModule {
+    #[only_owner]
+    #[endpoint(add_arbiter)]
+    fn add_arbiter(&self, arbiter: ManagedAddress) {
+        self.arbiters().insert(arbiter);
+    }
+
+    #[only_owner]
+    #[endpoint(remove_arbiter)]
+    fn remove_arbiter(&self, arbiter: ManagedAddress) {
+        self.arbiters().swap_remove(&arbiter);
+    }
+
+    /// Applies to disputes opened afterwards.
+    #[only_owner]
+    #[endpoint(set_dispute_timeout)]
+    fn set_dispute_timeout(&self, timeout: DurationSeconds) {
+        self.dispute_timeout().set(timeout);
+    }
+}
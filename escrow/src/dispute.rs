@@ -0,0 +1,159 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::events::DisputeResolvedEventData;
+use crate::storage::{DisputeData, EscrowData, EscrowStatus, MilestoneStatus};
+
+#[multiversx_sc::module]
+pub trait DisputeModule:
+    common::cross_contract::CrossContractModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+    + common::fees::FeesModule
+{
+    /// Freeze an active escrow until an arbiter splits it or the dispute timeout passes.
+    /// Callable by the employer or the receiver. Blocks `release`, `release_milestone`
+    /// and `refund` meanwhile.
+    #[endpoint(open_dispute)]
+    fn open_dispute(&self, job_id: ManagedBuffer) {
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+
+        let mut escrow = escrow_mapper.get();
+        self.require_active(&escrow);
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == escrow.employer || caller == escrow.receiver,
+            ERR_NOT_ESCROW_PARTY
+        );
+
+        let deadline =
+            self.blockchain().get_block_timestamp_seconds() + self.dispute_timeout().get();
+        self.escrow_dispute(&job_id).set(DisputeData {
+            opened_by: caller.clone(),
+            deadline,
+        });
+        escrow.status = EscrowStatus::Disputed;
+        escrow_mapper.set(&escrow);
+
+        self.escrow_dispute_opened_event(&job_id, &caller, deadline);
+    }
+
+    /// Split the remaining funds of a disputed escrow: `receiver_amount` to the receiver,
+    /// the rest back to the employer. Only callable by an arbiter who is not a party to it.
+    /// Milestones still pending are settled by the split and marked `Resolved`.
+    #[endpoint(resolve_dispute)]
+    fn resolve_dispute(&self, job_id: ManagedBuffer, receiver_amount: BigUint) {
+        let mut escrow = self.require_disputed(&job_id);
+
+        let caller = self.blockchain().get_caller();
+        require!(self.arbiters().contains(&caller), ERR_NOT_ARBITER);
+        require!(
+            caller != escrow.employer && caller != escrow.receiver,
+            ERR_ARBITER_IS_PARTY
+        );
+        let remaining = escrow.remaining();
+        require!(receiver_amount <= remaining, ERR_SHARE_EXCEEDS_ESCROW);
+
+        for index in 0..escrow.milestones.len() {
+            let mut milestone = escrow.milestones.get(index).clone();
+            if milestone.status == MilestoneStatus::Pending {
+                milestone.status = MilestoneStatus::Resolved;
+                let _ = escrow.milestones.set(index, milestone);
+            }
+        }
+
+        let employer_amount = remaining - &receiver_amount;
+        self.settle_dispute(&job_id, escrow, receiver_amount, employer_amount, &caller);
+    }
+
+    /// Resolve a dispute the arbiters left open past its deadline, in favour of the
+    /// verified party: a verified job pays the receiver, anything else refunds the employer.
+    /// For milestone escrows each pending milestone goes to the receiver if its deliverable
+    /// passed validation, to the employer otherwise. Anyone can call this.
+    #[endpoint(resolve_expired_dispute)]
+    fn resolve_expired_dispute(&self, job_id: ManagedBuffer) {
+        let mut escrow = self.require_disputed(&job_id);
+        require!(
+            self.blockchain().get_block_timestamp_seconds()
+                > self.escrow_dispute(&job_id).get().deadline,
+            ERR_DISPUTE_NOT_EXPIRED
+        );
+
+        let remaining = escrow.remaining();
+        let receiver_amount = if escrow.milestones.is_empty() {
+            if self.is_job_verified(&job_id, &escrow) {
+                remaining.clone()
+            } else {
+                BigUint::zero()
+            }
+        } else {
+            let mut receiver_amount = BigUint::zero();
+            for index in 0..escrow.milestones.len() {
+                let mut milestone = escrow.milestones.get(index).clone();
+                if milestone.status != MilestoneStatus::Pending {
+                    continue;
+                }
+                if self.is_deliverable_passed(&job_id, &escrow, &milestone.deliverable_hash) {
+                    receiver_amount += &milestone.amount;
+                    milestone.status = MilestoneStatus::Released;
+                } else {
+                    milestone.status = MilestoneStatus::Refunded;
+                }
+                let _ = escrow.milestones.set(index, milestone);
+            }
+            receiver_amount
+        };
+
+        let employer_amount = remaining - &receiver_amount;
+        let caller = self.blockchain().get_caller();
+        self.settle_dispute(&job_id, escrow, receiver_amount, employer_amount, &caller);
+    }
+
+    fn require_disputed(&self, job_id: &ManagedBuffer) -> EscrowData<Self::Api> {
+        let escrow_mapper = self.escrow_data(job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+
+        let escrow = escrow_mapper.get();
+        require!(escrow.status == EscrowStatus::Disputed, ERR_NOT_DISPUTED);
+        escrow
+    }
+
+    fn settle_dispute(
+        &self,
+        job_id: &ManagedBuffer,
+        mut escrow: EscrowData<Self::Api>,
+        receiver_amount: BigUint,
+        employer_amount: BigUint,
+        resolver: &ManagedAddress,
+    ) {
+        // Effects: close the escrow BEFORE interactions
+        escrow.status = EscrowStatus::Resolved;
+        self.escrow_data(job_id).set(&escrow);
+        self.escrow_dispute(job_id).clear();
+
+        // Interactions: pay out both shares, the receiver's minus the protocol fee
+        let receiver_amount = if receiver_amount > 0u64 {
+            self.pay_receiver(&escrow, &receiver_amount)
+        } else {
+            receiver_amount
+        };
+        if employer_amount > 0u64 {
+            self.tx()
+                .to(&escrow.employer)
+                .egld_or_single_esdt(&escrow.token_id, escrow.token_nonce, &employer_amount)
+                .transfer();
+        }
+
+        self.escrow_dispute_resolved_event(
+            job_id,
+            resolver,
+            DisputeResolvedEventData {
+                receiver_amount,
+                employer_amount,
+            },
+        );
+    }
+}
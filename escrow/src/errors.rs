@@ -1,8 +1,32 @@
+pub use common::errors::{ERR_AGENT_NOT_FOUND, ERR_AGENT_PAUSED, ERR_JOB_NOT_FOUND};
+
 pub const ERR_ESCROW_ALREADY_EXISTS: &str = "Escrow already exists for this job";
 pub const ERR_ESCROW_NOT_FOUND: &str = "Escrow not found for this job";
-pub const ERR_NOT_EMPLOYER: &str = "Only the employer can call this";
+pub const ERR_NOT_EMPLOYER: &str = "Only the job employer can fund its escrow";
+pub const ERR_RECEIVER_NOT_AGENT_OWNER: &str = "Receiver must be the owner of the job agent";
 pub const ERR_JOB_NOT_VERIFIED: &str = "Job must be verified before release";
 pub const ERR_DEADLINE_NOT_PASSED: &str = "Deadline has not passed yet";
+pub const ERR_VERIFIED_NOT_REFUNDABLE: &str = "Verified work must be released, not refunded";
 pub const ERR_DEADLINE_IN_PAST: &str = "Deadline must be in the future";
 pub const ERR_ALREADY_SETTLED: &str = "Escrow already settled";
 pub const ERR_ZERO_DEPOSIT: &str = "Deposit amount must be greater than zero";
+pub const ERR_NO_MILESTONES: &str = "At least one milestone is required";
+pub const ERR_TOO_MANY_MILESTONES: &str = "Too many milestones";
+pub const ERR_ZERO_MILESTONE: &str = "Milestone amount must be greater than zero";
+pub const ERR_DUPLICATE_DELIVERABLE: &str = "Deliverable hash used by more than one milestone";
+pub const ERR_MILESTONE_SUM_MISMATCH: &str = "Milestone amounts must add up to the deposit";
+pub const ERR_MILESTONE_ESCROW: &str = "Milestone escrows are released one milestone at a time";
+pub const ERR_NOT_MILESTONE_ESCROW: &str = "Escrow has no milestones";
+pub const ERR_NOT_MILESTONE_JOB: &str = "Milestone escrows need a job with milestones enabled";
+pub const ERR_MILESTONE_JOB: &str = "Jobs with milestones enabled need a milestone escrow";
+pub const ERR_MILESTONE_NOT_FOUND: &str = "Milestone not found";
+pub const ERR_MILESTONE_SETTLED: &str = "Milestone already settled";
+pub const ERR_MILESTONE_NOT_VERIFIED: &str =
+    "Deliverable must pass validation for this job before release";
+pub const ERR_ESCROW_DISPUTED: &str = "Escrow is under dispute";
+pub const ERR_NOT_DISPUTED: &str = "Escrow is not under dispute";
+pub const ERR_NOT_ESCROW_PARTY: &str = "Only the employer or the receiver can call this";
+pub const ERR_NOT_ARBITER: &str = "Only an arbiter can call this";
+pub const ERR_ARBITER_IS_PARTY: &str = "Arbiter cannot resolve a dispute it is party to";
+pub const ERR_SHARE_EXCEEDS_ESCROW: &str = "Receiver share exceeds the escrowed funds";
+pub const ERR_DISPUTE_NOT_EXPIRED: &str = "Dispute timeout has not passed yet";
@@ -1,4 +1,12 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DisputeResolvedEventData<M: ManagedTypeApi> {
+    pub receiver_amount: BigUint<M>,
+    pub employer_amount: BigUint<M>,
+}
 
 #[multiversx_sc::module]
 pub trait EventsModule {
@@ -18,6 +26,15 @@ pub trait EventsModule {
         amount: BigUint,
     );
 
+    #[event("escrow_milestone_released")]
+    fn escrow_milestone_released_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] milestone: u32,
+        amount: BigUint,
+    );
+
     #[event("escrow_refunded")]
     fn escrow_refunded_event(
         &self,
@@ -25,4 +42,21 @@ pub trait EventsModule {
         #[indexed] employer: &ManagedAddress,
         amount: BigUint,
     );
+
+    #[event("escrow_dispute_opened")]
+    fn escrow_dispute_opened_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] opened_by: &ManagedAddress,
+        deadline: TimestampSeconds,
+    );
+
+    /// `resolver` is the arbiter, or the caller of `resolve_expired_dispute`.
+    #[event("escrow_dispute_resolved")]
+    fn escrow_dispute_resolved_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] resolver: &ManagedAddress,
+        data: DisputeResolvedEventData<Self::Api>,
+    );
 }
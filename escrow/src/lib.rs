@@ -3,20 +3,36 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod config;
+pub mod dispute;
 pub mod errors;
 pub mod events;
 pub mod storage;
+pub mod utils;
+pub mod views;
 
 use errors::*;
-use storage::{EscrowData, EscrowStatus};
+use storage::{EscrowData, EscrowStatus, LegacyEscrowData, Milestone, MilestoneStatus};
+
+pub const MAX_MILESTONES: usize = 20;
+const DEFAULT_DISPUTE_TIMEOUT: DurationSeconds = DurationSeconds::new(7 * 24 * 60 * 60);
 
 /// ACP Escrow Contract — locks funds for agent jobs, releases on proof verification,
-/// refunds if deadline passes without verified proof.
+/// refunds if the job is rejected or cancelled, or the deadline passes without verified proof.
+/// Either party can freeze an escrow in a dispute that an arbiter settles.
+/// Payouts to the receiver carry the protocol fee.
 ///
 /// Follows Checks-Effects-Interactions pattern throughout.
 #[multiversx_sc::contract]
 pub trait EscrowContract:
-    common::cross_contract::CrossContractModule + storage::StorageModule + events::EventsModule
+    common::cross_contract::CrossContractModule
+    + storage::StorageModule
+    + events::EventsModule
+    + views::ViewsModule
+    + utils::UtilsModule
+    + config::ConfigModule
+    + dispute::DisputeModule
+    + common::fees::FeesModule
 {
     #[init]
     fn init(
@@ -28,13 +44,58 @@ pub trait EscrowContract:
             .set(&validation_contract_address);
         self.identity_contract_address()
             .set(&identity_contract_address);
+        self.dispute_timeout().set(DEFAULT_DISPUTE_TIMEOUT);
     }
 
+    /// Re-encodes the escrows of `job_ids` stored before milestones existed
+    /// as lump-sum escrows, bound to the job currently stored under the same id if the
+    /// employer matches. Escrows already in the current layout are left as they are.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, job_ids: MultiValueEncoded<ManagedBuffer>) {
+        if self.dispute_timeout().is_empty() {
+            self.dispute_timeout().set(DEFAULT_DISPUTE_TIMEOUT);
+        }
+        for job_id in job_ids {
+            let raw = self.raw_escrow_data(&job_id).get();
+            if raw.is_empty() || EscrowData::<Self::Api>::top_decode(raw.clone()).is_ok() {
+                continue;
+            }
+            let Ok(legacy) = LegacyEscrowData::<Self::Api>::top_decode(raw) else {
+                continue;
+            };
+
+            // Legacy deposits were not checked against a job; with no matching job they
+            // can only be refunded after their deadline
+            let job_mapper =
+                self.external_job_data(self.validation_contract_address().get(), &job_id);
+            let (agent_nonce, job_creation_timestamp) =
+                if !job_mapper.is_empty() && job_mapper.get().employer == legacy.employer {
+                    let job = job_mapper.get();
+                    (job.agent_nonce, job.creation_timestamp)
+                } else {
+                    (0, TimestampMillis::zero())
+                };
+
+            self.escrow_data(&job_id).set(EscrowData {
+                employer: legacy.employer,
+                receiver: legacy.receiver,
+                token_id: legacy.token_id,
+                token_nonce: legacy.token_nonce,
+                amount: legacy.amount,
+                poa_hash: legacy.poa_hash,
+                deadline: legacy.deadline,
+                status: legacy.status,
+                milestones: ManagedVec::new(),
+                protocol_fee_bps: 0,
+                agent_nonce,
+                job_creation_timestamp,
+            });
+        }
+    }
 
     /// Deposit funds into escrow for a specific job.
-    /// Accepts EGLD or any ESDT token.
+    /// Accepts EGLD or any ESDT token. Only the job's employer can deposit, and `receiver`
+    /// must be the owner of the job's agent; pass the zero address to use it automatically.
     /// `deadline` is a Unix timestamp (seconds) after which a refund is allowed.
     #[payable("*")]
     #[endpoint(deposit)]
@@ -44,6 +105,60 @@ pub trait EscrowContract:
         receiver: ManagedAddress,
         poa_hash: ManagedBuffer,
         deadline: TimestampSeconds,
+    ) {
+        self.create_escrow(job_id, receiver, poa_hash, deadline, ManagedVec::new());
+    }
+
+    /// Deposit funds split into ordered milestones of `(amount, deliverable_hash)`.
+    /// The amounts must add up to the payment. Each milestone is released on its own
+    /// with `release_milestone` once the validation request `deliverable_hash` passes
+    /// for this job. The job must have milestones enabled in the validation registry
+    /// (`enable_job_milestones`), so each deliverable is validated without closing the job.
+    /// The job and receiver are checked as in `deposit`.
+    #[payable("*")]
+    #[endpoint(deposit_milestones)]
+    fn deposit_milestones(
+        &self,
+        job_id: ManagedBuffer,
+        receiver: ManagedAddress,
+        poa_hash: ManagedBuffer,
+        deadline: TimestampSeconds,
+        milestones: MultiValueEncoded<MultiValue2<BigUint, ManagedBuffer>>,
+    ) {
+        require!(!milestones.is_empty(), ERR_NO_MILESTONES);
+        require!(milestones.len() <= MAX_MILESTONES, ERR_TOO_MANY_MILESTONES);
+
+        let mut total = BigUint::zero();
+        let mut list = ManagedVec::<Self::Api, Milestone<Self::Api>>::new();
+        for milestone in milestones {
+            let (amount, deliverable_hash) = milestone.into_tuple();
+            require!(amount > 0u64, ERR_ZERO_MILESTONE);
+            require!(
+                !list.iter().any(|m| m.deliverable_hash == deliverable_hash),
+                ERR_DUPLICATE_DELIVERABLE
+            );
+            total += &amount;
+            list.push(Milestone {
+                amount,
+                deliverable_hash,
+                status: MilestoneStatus::Pending,
+            });
+        }
+        require!(
+            total == self.call_value().egld_or_single_esdt().amount,
+            ERR_MILESTONE_SUM_MISMATCH
+        );
+
+        self.create_escrow(job_id, receiver, poa_hash, deadline, list);
+    }
+
+    fn create_escrow(
+        &self,
+        job_id: ManagedBuffer,
+        receiver: ManagedAddress,
+        poa_hash: ManagedBuffer,
+        deadline: TimestampSeconds,
+        milestones: ManagedVec<Milestone<Self::Api>>,
     ) {
         let payment = self.call_value().egld_or_single_esdt();
         require!(payment.amount > 0u64, ERR_ZERO_DEPOSIT);
@@ -54,7 +169,37 @@ pub trait EscrowContract:
         let escrow_mapper = self.escrow_data(&job_id);
         require!(escrow_mapper.is_empty(), ERR_ESCROW_ALREADY_EXISTS);
 
+        // Cross-contract check: the job exists and belongs to the caller
         let caller = self.blockchain().get_caller();
+        let validation_addr = self.validation_contract_address().get();
+        let job_mapper = self.external_job_data(validation_addr.clone(), &job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+        let job = job_mapper.get();
+        require!(caller == job.employer, ERR_NOT_EMPLOYER);
+        let milestone_job = self.external_job_milestones(validation_addr, &job_id).get();
+        if milestones.is_empty() {
+            require!(!milestone_job, ERR_MILESTONE_JOB);
+        } else {
+            require!(milestone_job, ERR_NOT_MILESTONE_JOB);
+        }
+
+        // Cross-contract check: the agent is active and the funds go to its owner
+        let identity_addr = self.identity_contract_address().get();
+        require!(
+            self.external_agent_status(identity_addr.clone(), job.agent_nonce)
+                .get()
+                == common::structs::AgentStatus::Active,
+            ERR_AGENT_PAUSED
+        );
+        let owner_mapper = self.external_agent_owner(identity_addr, job.agent_nonce);
+        require!(!owner_mapper.is_empty(), ERR_AGENT_NOT_FOUND);
+        let agent_owner = owner_mapper.get();
+        let receiver = if receiver.is_zero() {
+            agent_owner
+        } else {
+            require!(receiver == agent_owner, ERR_RECEIVER_NOT_AGENT_OWNER);
+            receiver
+        };
 
         let escrow = EscrowData {
             employer: caller.clone(),
@@ -65,6 +210,10 @@ pub trait EscrowContract:
             poa_hash,
             deadline,
             status: EscrowStatus::Active,
+            milestones,
+            protocol_fee_bps: self.protocol_fee_bps().get(),
+            agent_nonce: job.agent_nonce,
+            job_creation_timestamp: job.creation_timestamp,
         };
 
         // Effects: store escrow
@@ -74,24 +223,21 @@ pub trait EscrowContract:
     }
 
     /// Release escrowed funds to the receiver.
-    /// Only callable by the employer. Job must be verified in the ValidationRegistry.
+    /// Anyone can call this once the job is verified in the ValidationRegistry.
+    /// Milestone escrows use `release_milestone` instead.
     #[endpoint(release)]
     fn release(&self, job_id: ManagedBuffer) {
         let escrow_mapper = self.escrow_data(&job_id);
         require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
 
         let mut escrow = escrow_mapper.get();
-        require!(escrow.status == EscrowStatus::Active, ERR_ALREADY_SETTLED);
+        self.require_active(&escrow);
+        require!(escrow.milestones.is_empty(), ERR_MILESTONE_ESCROW);
 
-        let caller = self.blockchain().get_caller();
-        require!(caller == escrow.employer, ERR_NOT_EMPLOYER);
-
-        // Cross-contract check: read job status from validation-registry
-        let validation_addr = self.validation_contract_address().get();
-        let job_mapper = self.external_job_data(validation_addr, &job_id);
-        require!(!job_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
-
-        let job_data = job_mapper.get();
+        // Cross-contract check: read the status of the escrow's job from validation-registry
+        let Some(job_data) = self.escrow_job(&job_id, &escrow) else {
+            sc_panic!(ERR_JOB_NOT_FOUND);
+        };
         require!(
             job_data.status == common::structs::JobStatus::Verified,
             ERR_JOB_NOT_VERIFIED
@@ -99,38 +245,103 @@ pub trait EscrowContract:
 
         // Effects: mark as released BEFORE interactions
         escrow.status = EscrowStatus::Released;
-        let receiver = escrow.receiver.clone();
-        let amount = escrow.amount.clone();
-        let token_id = escrow.token_id.clone();
-        let token_nonce = escrow.token_nonce;
         escrow_mapper.set(&escrow);
 
         // Interactions: transfer funds to receiver
-        self.tx()
-            .to(&receiver)
-            .egld_or_single_esdt(&token_id, token_nonce, &amount)
-            .transfer();
+        let amount = self.pay_receiver(&escrow, &escrow.amount);
+
+        self.escrow_released_event(&job_id, &escrow.receiver, amount);
+    }
+
+    /// Release one milestone to the receiver. Anyone can call this once the validation
+    /// request named by the milestone's `deliverable_hash` belongs to this job and has
+    /// passed. The escrow is `Released` once its last milestone is.
+    #[endpoint(release_milestone)]
+    fn release_milestone(&self, job_id: ManagedBuffer, milestone_index: u32) {
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+
+        let mut escrow = escrow_mapper.get();
+        self.require_active(&escrow);
+        require!(!escrow.milestones.is_empty(), ERR_NOT_MILESTONE_ESCROW);
+
+        let index = milestone_index as usize;
+        require!(index < escrow.milestones.len(), ERR_MILESTONE_NOT_FOUND);
+        let mut milestone = escrow.milestones.get(index).clone();
+        require!(
+            milestone.status == MilestoneStatus::Pending,
+            ERR_MILESTONE_SETTLED
+        );
 
-        self.escrow_released_event(&job_id, &receiver, amount);
+        // Cross-contract check: the deliverable's validation request passed for this job
+        require!(
+            self.is_deliverable_passed(&job_id, &escrow, &milestone.deliverable_hash),
+            ERR_MILESTONE_NOT_VERIFIED
+        );
+
+        // Effects: mark the milestone (and the escrow, if it was the last one) BEFORE interactions
+        milestone.status = MilestoneStatus::Released;
+        let amount = milestone.amount.clone();
+        let _ = escrow.milestones.set(index, milestone);
+        if escrow
+            .milestones
+            .iter()
+            .all(|m| m.status == MilestoneStatus::Released)
+        {
+            escrow.status = EscrowStatus::Released;
+        }
+        escrow_mapper.set(&escrow);
+
+        // Interactions: transfer the milestone to receiver
+        let amount = self.pay_receiver(&escrow, &amount);
+
+        self.escrow_milestone_released_event(&job_id, &escrow.receiver, milestone_index, amount);
     }
 
-    /// Refund escrowed funds to the employer if the deadline has passed.
-    /// Anyone can call this (allows automated cleanup).
+    /// Refund escrowed funds to the employer once the deadline has passed, or right away
+    /// if the validator rejected the job or the employer cancelled it. Only the job the
+    /// escrow was funded against counts: one initialised again under the same id after a
+    /// cleanup does not shorten the wait. Milestone jobs are never rejected: a failed
+    /// deliverable waits for the deadline. Anyone can call this (allows automated cleanup). Verified work is never refunded: a verified job, or a
+    /// pending milestone whose deliverable passed, must be released first. Milestones
+    /// already released stay with the receiver; the pending ones are refunded.
     #[endpoint(refund)]
     fn refund(&self, job_id: ManagedBuffer) {
         let escrow_mapper = self.escrow_data(&job_id);
         require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
 
         let mut escrow = escrow_mapper.get();
-        require!(escrow.status == EscrowStatus::Active, ERR_ALREADY_SETTLED);
+        self.require_active(&escrow);
+        require!(
+            !self.has_verified_work(&job_id, &escrow),
+            ERR_VERIFIED_NOT_REFUNDABLE
+        );
+
+        // Cross-contract check: a rejected or cancelled job does not need to wait for the deadline
+        let job_closed = self.escrow_job(&job_id, &escrow).is_some_and(|job| {
+            matches!(
+                job.status,
+                common::structs::JobStatus::Rejected | common::structs::JobStatus::Cancelled
+            )
+        });
 
         let current_timestamp = self.blockchain().get_block_timestamp_seconds();
-        require!(current_timestamp > escrow.deadline, ERR_DEADLINE_NOT_PASSED);
+        require!(
+            job_closed || current_timestamp > escrow.deadline,
+            ERR_DEADLINE_NOT_PASSED
+        );
 
         // Effects: mark as refunded BEFORE interactions
+        let amount = escrow.remaining();
         escrow.status = EscrowStatus::Refunded;
+        for index in 0..escrow.milestones.len() {
+            let mut milestone = escrow.milestones.get(index).clone();
+            if milestone.status == MilestoneStatus::Pending {
+                milestone.status = MilestoneStatus::Refunded;
+                let _ = escrow.milestones.set(index, milestone);
+            }
+        }
         let employer = escrow.employer.clone();
-        let amount = escrow.amount.clone();
         let token_id = escrow.token_id.clone();
         let token_nonce = escrow.token_nonce;
         escrow_mapper.set(&escrow);
@@ -1,19 +1,19 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-/// Escrow settlement status.
+pub use common::structs::{EscrowData, EscrowStatus, Milestone, MilestoneStatus};
+
+/// Open dispute on an escrow. After `deadline` it can be resolved without an arbiter.
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
-pub enum EscrowStatus {
-    Active,
-    Released,
-    Refunded,
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DisputeData<M: ManagedTypeApi> {
+    pub opened_by: ManagedAddress<M>,
+    pub deadline: TimestampSeconds,
 }
 
-/// On-chain escrow record.
-#[type_abi]
+/// Layout of `EscrowData` before milestones, kept to migrate stored records on upgrade.
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
-pub struct EscrowData<M: ManagedTypeApi> {
+pub struct LegacyEscrowData<M: ManagedTypeApi> {
     pub employer: ManagedAddress<M>,
     pub receiver: ManagedAddress<M>,
     pub token_id: EgldOrEsdtTokenIdentifier<M>,
@@ -30,6 +30,23 @@ pub trait StorageModule {
     #[storage_mapper("escrowData")]
     fn escrow_data(&self, job_id: &ManagedBuffer) -> SingleValueMapper<EscrowData<Self::Api>>;
 
+    /// Raw bytes of `escrowData`, so `upgrade` can tell legacy records apart.
+    #[storage_mapper("escrowData")]
+    fn raw_escrow_data(&self, job_id: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("escrowDispute")]
+    fn escrow_dispute(&self, job_id: &ManagedBuffer) -> SingleValueMapper<DisputeData<Self::Api>>;
+
+    /// Addresses allowed to split the funds of a disputed escrow.
+    #[view(get_arbiters)]
+    #[storage_mapper("arbiters")]
+    fn arbiters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Time the arbiters have to resolve a dispute before it resolves by itself.
+    #[view(get_dispute_timeout)]
+    #[storage_mapper("disputeTimeout")]
+    fn dispute_timeout(&self) -> SingleValueMapper<DurationSeconds>;
+
     #[view(get_validation_contract_address)]
     #[storage_mapper("validationContractAddress")]
     fn validation_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
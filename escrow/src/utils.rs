@@ -0,0 +1,91 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::storage::{EscrowData, EscrowStatus, MilestoneStatus};
+use common::structs::{JobData, JobStatus};
+
+#[multiversx_sc::module]
+pub trait UtilsModule:
+    common::cross_contract::CrossContractModule
+    + crate::storage::StorageModule
+    + common::fees::FeesModule
+{
+    fn require_active(&self, escrow: &EscrowData<Self::Api>) {
+        require!(escrow.status != EscrowStatus::Disputed, ERR_ESCROW_DISPUTED);
+        require!(escrow.status == EscrowStatus::Active, ERR_ALREADY_SETTLED);
+    }
+
+    /// Transfer `amount` of the escrowed token to the receiver, minus the protocol fee
+    /// recorded on the escrow. Returns what the receiver got.
+    fn pay_receiver(&self, escrow: &EscrowData<Self::Api>, amount: &BigUint) -> BigUint {
+        let net_amount = self.take_protocol_fee(
+            &TokenId::from(escrow.token_id.clone()),
+            escrow.token_nonce,
+            amount,
+            escrow.protocol_fee_bps,
+        );
+        self.tx()
+            .to(&escrow.receiver)
+            .egld_or_single_esdt(&escrow.token_id, escrow.token_nonce, &net_amount)
+            .transfer();
+        net_amount
+    }
+
+    /// The job the escrow was funded against, read from validation-registry. `None` once
+    /// the job was cleaned, even if someone has initialised the same id again since.
+    fn escrow_job(
+        &self,
+        job_id: &ManagedBuffer,
+        escrow: &EscrowData<Self::Api>,
+    ) -> Option<JobData<Self::Api>> {
+        let validation_addr = self.validation_contract_address().get();
+        let job_mapper = self.external_job_data(validation_addr, job_id);
+        if job_mapper.is_empty() {
+            return None;
+        }
+
+        let job = job_mapper.get();
+        let same_job = job.employer == escrow.employer
+            && job.agent_nonce == escrow.agent_nonce
+            && job.creation_timestamp == escrow.job_creation_timestamp;
+        same_job.then_some(job)
+    }
+
+    fn is_job_verified(&self, job_id: &ManagedBuffer, escrow: &EscrowData<Self::Api>) -> bool {
+        self.escrow_job(job_id, escrow)
+            .is_some_and(|job| job.status == JobStatus::Verified)
+    }
+
+    /// Whether the validation request `deliverable_hash` belongs to the escrow's job and passed.
+    fn is_deliverable_passed(
+        &self,
+        job_id: &ManagedBuffer,
+        escrow: &EscrowData<Self::Api>,
+        deliverable_hash: &ManagedBuffer,
+    ) -> bool {
+        if self.escrow_job(job_id, escrow).is_none() {
+            return false;
+        }
+
+        let validation_addr = self.validation_contract_address().get();
+        let request_mapper =
+            self.external_validation_request_data(validation_addr.clone(), deliverable_hash);
+        !request_mapper.is_empty()
+            && request_mapper.get().job_id == *job_id
+            && self
+                .external_request_passed(validation_addr, deliverable_hash)
+                .get()
+    }
+
+    /// Whether part of the escrow is owed to the receiver: the job is verified for a
+    /// lump-sum escrow, or a pending milestone's deliverable passed.
+    fn has_verified_work(&self, job_id: &ManagedBuffer, escrow: &EscrowData<Self::Api>) -> bool {
+        if escrow.milestones.is_empty() {
+            return self.is_job_verified(job_id, escrow);
+        }
+        escrow.milestones.iter().any(|m| {
+            m.status == MilestoneStatus::Pending
+                && self.is_deliverable_passed(job_id, escrow, &m.deliverable_hash)
+        })
+    }
+}
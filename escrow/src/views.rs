@@ -0,0 +1,46 @@
+multiversx_sc::imports!();
+
+use crate::storage::{DisputeData, Milestone};
+
+#[multiversx_sc::module]
+pub trait ViewsModule: crate::storage::StorageModule {
+    /// Funds still locked in the escrow. Zero for unknown or settled escrows.
+    #[view(get_escrow_remaining)]
+    fn get_escrow_remaining(&self, job_id: ManagedBuffer) -> BigUint {
+        let escrow_mapper = self.escrow_data(&job_id);
+        if escrow_mapper.is_empty() {
+            return BigUint::zero();
+        }
+        escrow_mapper.get().remaining()
+    }
+
+    #[view(get_milestone)]
+    fn get_milestone(
+        &self,
+        job_id: ManagedBuffer,
+        milestone_index: u32,
+    ) -> OptionalValue<Milestone<Self::Api>> {
+        let escrow_mapper = self.escrow_data(&job_id);
+        if escrow_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let milestones = escrow_mapper.get().milestones;
+        let index = milestone_index as usize;
+        if index < milestones.len() {
+            OptionalValue::Some(milestones.get(index).clone())
+        } else {
+            OptionalValue::None
+        }
+    }
+
+    #[view(get_dispute)]
+    fn get_dispute(&self, job_id: ManagedBuffer) -> OptionalValue<DisputeData<Self::Api>> {
+        let dispute_mapper = self.escrow_dispute(&job_id);
+        if dispute_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(dispute_mapper.get())
+        }
+    }
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            6
+// Endpoints:                           25
 // Async Callback (empty):               1
-// Total number of exported functions:   9
+// Total number of exported functions:  28
 
 #![no_std]
 
@@ -21,11 +21,30 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         deposit => deposit
+        deposit_milestones => deposit_milestones
         release => release
+        release_milestone => release_milestone
         refund => refund
         get_escrow => escrow_data
+        get_arbiters => arbiters
+        get_dispute_timeout => dispute_timeout
         get_validation_contract_address => validation_contract_address
         get_identity_contract_address => identity_contract_address
+        get_escrow_remaining => get_escrow_remaining
+        get_milestone => get_milestone
+        get_dispute => get_dispute
+        add_arbiter => add_arbiter
+        remove_arbiter => remove_arbiter
+        set_dispute_timeout => set_dispute_timeout
+        open_dispute => open_dispute
+        resolve_dispute => resolve_dispute
+        resolve_expired_dispute => resolve_expired_dispute
+        set_protocol_fee => set_protocol_fee
+        set_treasury => set_treasury
+        claim_fees => claim_fees
+        get_accumulated_fees => get_accumulated_fees
+        get_protocol_fee_bps => protocol_fee_bps
+        get_treasury => treasury
     )
 }
 
@@ -62,12 +62,18 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn upgrade(
+    /// `agent_nonces`: agents still in the legacy 1:1 owner map, migrated in batches so a 
+    /// large registry never has to fit in one upgrade. Other nonces are skipped. 
+    pub fn upgrade<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
         self,
+        agent_nonces: Arg0,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
+            .argument(&agent_nonces)
             .original_result()
     }
 }
@@ -96,7 +102,39 @@ where
             .original_result()
     }
 
+    /// Allow (or disallow) a single address to own several agents. 
+    /// Disabling does not affect owners who already hold more than one agent. 
+    pub fn set_multi_agent_mode<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_multi_agent_mode")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Move agents out of the legacy 1:1 owner map between upgrades, in as many batches as 
+    /// needed. Until then cross-contract readers of `agentOwner` do not find them. 
+    /// Unknown or already migrated nonces are skipped. 
+    pub fn migrate_legacy_agents<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        agent_nonces: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrate_legacy_agents")
+            .argument(&agent_nonces)
+            .original_result()
+    }
+
     /// Register a new agent with name, URI, public key, optional metadata, and optional service configs. 
+    /// The identity NFT is minted into registry custody and never leaves it. 
     pub fn register_agent<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -122,23 +160,28 @@ where
             .original_result()
     }
 
-    /// Update an agent's URI and/or public_key. Requires sending the agent NFT. 
+    /// Update an agent's name, URI and public_key. The custodied NFT is recreated in place 
+    /// and `agent_details` is kept in sync, so a new public key takes effect for signed proofs. 
     pub fn update_agent<
-        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg3: ProxyArg<OptionalValue<MultiValueEncoded<Env::Api, common::structs::MetadataEntry<Env::Api>>>>,
-        Arg4: ProxyArg<OptionalValue<MultiValueEncoded<Env::Api, common::structs::ServiceConfigInput<Env::Api>>>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<OptionalValue<MultiValueEncoded<Env::Api, common::structs::MetadataEntry<Env::Api>>>>,
+        Arg5: ProxyArg<OptionalValue<MultiValueEncoded<Env::Api, common::structs::ServiceConfigInput<Env::Api>>>>,
     >(
         self,
-        new_name: Arg0,
-        new_uri: Arg1,
-        new_public_key: Arg2,
-        metadata: Arg3,
-        services: Arg4,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        nonce: Arg0,
+        new_name: Arg1,
+        new_uri: Arg2,
+        new_public_key: Arg3,
+        metadata: Arg4,
+        services: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
+            .payment(NotPayable)
             .raw_call("update_agent")
+            .argument(&nonce)
             .argument(&new_name)
             .argument(&new_uri)
             .argument(&new_public_key)
@@ -147,7 +190,20 @@ where
             .original_result()
     }
 
+    /// Hand over the identity NFT of an agent registered before NFTs were custodied, which 
+    /// still sits in a wallet and can be transferred freely until then. Holding it grants 
+    /// nothing: only the owner recorded in `agentOwner` can deposit it, whoever sent it. 
+    /// Until it is deposited, `update_agent` and `deregister_agent` are rejected for that agent. 
+    pub fn custody_agent_nft(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("custody_agent_nft")
+            .original_result()
+    }
+
     /// Set or update metadata entries for an agent. O(1) per entry via MapMapper. 
+    /// Callable by the owner or an operator with `PERMISSION_EDIT_METADATA`. 
     pub fn set_metadata<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<MultiValueEncoded<Env::Api, common::structs::MetadataEntry<Env::Api>>>,
@@ -165,6 +221,7 @@ where
     }
 
     /// Set or update service configurations for an agent. 
+    /// Callable by the owner or an operator with `PERMISSION_EDIT_SERVICES`. 
     pub fn set_service_configs_endpoint<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<MultiValueEncoded<Env::Api, common::structs::ServiceConfigInput<Env::Api>>>,
@@ -215,6 +272,179 @@ where
             .original_result()
     }
 
+    /// Require validator responses of at least `threshold` (0-100) before jobs for `service_id` 
+    /// count as verified. Validation-registry's global threshold still applies as a floor. 
+    /// Callable by the owner or an operator with `PERMISSION_EDIT_SERVICES`. 
+    pub fn set_service_pass_threshold<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<u8>,
+    >(
+        self,
+        nonce: Arg0,
+        service_id: Arg1,
+        threshold: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_service_pass_threshold")
+            .argument(&nonce)
+            .argument(&service_id)
+            .argument(&threshold)
+            .original_result()
+    }
+
+    /// Have validation-registry hold payments for `service_id` until the job is verified, 
+    /// instead of forwarding them to the owner when the job is opened. 
+    /// Callable by the owner or an operator with `PERMISSION_EDIT_SERVICES`. 
+    pub fn set_service_pay_on_verification<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<bool>,
+    >(
+        self,
+        nonce: Arg0,
+        service_id: Arg1,
+        enabled: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_service_pay_on_verification")
+            .argument(&nonce)
+            .argument(&service_id)
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Grant `operator` the given permission bitmask on an agent, replacing any previous grant. 
+    pub fn set_agent_operator<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u8>,
+    >(
+        self,
+        nonce: Arg0,
+        operator: Arg1,
+        permissions: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_agent_operator")
+            .argument(&nonce)
+            .argument(&operator)
+            .argument(&permissions)
+            .original_result()
+    }
+
+    /// Revoke all permissions of `operator` on an agent. 
+    pub fn remove_agent_operator<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        nonce: Arg0,
+        operator: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_agent_operator")
+            .argument(&nonce)
+            .argument(&operator)
+            .original_result()
+    }
+
+    /// Propose handing an agent over to `new_owner`. Takes effect only once accepted. 
+    /// A new proposal replaces any pending one. 
+    pub fn propose_agent_transfer<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        nonce: Arg0,
+        new_owner: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("propose_agent_transfer")
+            .argument(&nonce)
+            .argument(&new_owner)
+            .original_result()
+    }
+
+    /// Accept a pending transfer. Caller must be the proposed owner and, unless 
+    /// multi-agent mode is on, must not own an agent yet. Operators of the previous owner are revoked. 
+    /// The NFT stays custodied by the registry — only the recorded owner changes. 
+    pub fn accept_agent_transfer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("accept_agent_transfer")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Cancel a pending transfer proposal. 
+    pub fn cancel_agent_transfer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_agent_transfer")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Temporarily take an agent out of service. Identity, metadata and reputation are kept; 
+    /// validation-registry refuses new jobs until the agent is resumed. 
+    pub fn pause_agent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause_agent")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Put a paused agent back into service. 
+    pub fn resume_agent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resume_agent")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Retire an agent: burn the custodied identity NFT and clear all of its storage. 
+    /// The nonce is never reused, so cross-contract readers see the agent as not found. 
+    pub fn deregister_agent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("deregister_agent")
+            .argument(&nonce)
+            .original_result()
+    }
+
     pub fn agent_token_id(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
@@ -224,12 +454,12 @@ where
             .original_result()
     }
 
-    pub fn agents(
+    pub fn multi_agent_mode(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, ManagedAddress<Env::Api>>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("get_agent_id")
+            .raw_call("is_multi_agent_mode")
             .original_result()
     }
 
@@ -259,12 +489,14 @@ where
             .original_result()
     }
 
+    /// Service ID -> payments the service accepts, any one of which pays for a job. 
+    /// A single legacy `Payment` decodes as a one-entry list. 
     pub fn agent_service_config<
         Arg0: ProxyArg<u64>,
     >(
         self,
         nonce: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, Payment<Env::Api>>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, ManagedVec<Env::Api, Payment<Env::Api>>>>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("get_agent_service")
@@ -272,6 +504,19 @@ where
             .original_result()
     }
 
+    pub fn agent_status<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::AgentStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_status")
+            .argument(&nonce)
+            .original_result()
+    }
+
     pub fn get_agent<
         Arg0: ProxyArg<u64>,
     >(
@@ -298,6 +543,82 @@ where
             .original_result()
     }
 
+    /// Agent nonces owned by `owner`, paginated. `from` is 0-based; order is not stable across removals. 
+    pub fn get_agents_by_owner<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        owner: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agents_by_owner")
+            .argument(&owner)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    pub fn get_agent_count_by_owner<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_count_by_owner")
+            .argument(&owner)
+            .original_result()
+    }
+
+    pub fn get_pending_agent_transfer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_pending_agent_transfer")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Permission bitmask of `operator` on an agent; 0 if it is not an operator. 
+    pub fn get_operator_permissions<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        nonce: Arg0,
+        operator: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_operator_permissions")
+            .argument(&nonce)
+            .argument(&operator)
+            .original_result()
+    }
+
+    pub fn get_agent_operators<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, u8>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_operators")
+            .argument(&nonce)
+            .original_result()
+    }
+
     pub fn get_metadata<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -314,6 +635,7 @@ where
             .original_result()
     }
 
+    /// First accepted payment of a service. See `get_agent_service_payments` for all of them. 
     pub fn get_agent_service_config<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<u32>,
@@ -329,4 +651,52 @@ where
             .argument(&service_id)
             .original_result()
     }
+
+    pub fn get_agent_service_payments<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_service_payments")
+            .argument(&nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    pub fn is_service_pay_on_verification<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_service_pay_on_verification")
+            .argument(&nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    pub fn get_service_pass_threshold<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u8>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_service_pass_threshold")
+            .argument(&nonce)
+            .argument(&service_id)
+            .original_result()
+    }
 }
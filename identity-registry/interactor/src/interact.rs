@@ -33,7 +33,7 @@ pub async fn identity_registry_cli() {
         "remove_metadata" => interact.remove_metadata().await,
         "remove_service_configs" => interact.remove_service_configs().await,
         "get_agent_token_id" => interact.agent_token_id().await,
-        "get_agent_id" => interact.agents().await,
+        "get_agents_by_owner" => interact.get_agents_by_owner().await,
         "get_agent_details" => interact.agent_details().await,
         "get_agent_metadata" => interact.agent_metadata().await,
         "get_agent_service" => interact.agent_service_config().await,
@@ -143,7 +143,7 @@ impl ContractInteract {
             .from(&self.wallet_address)
             .gas(30_000_000u64)
             .typed(identity_registry_proxy::IdentityRegistryProxy)
-            .upgrade()
+            .upgrade(MultiValueVec::<u64>::new())
             .code(&self.contract_code)
             .code_metadata(CodeMetadata::UPGRADEABLE)
             .returns(ReturnsResultUnmanaged)
@@ -198,10 +198,7 @@ impl ContractInteract {
     }
 
     pub async fn update_agent(&mut self) {
-        let token_id = String::new();
-        let token_nonce = 0u64;
-        let token_amount = BigUint::<StaticApi>::from(0u128);
-
+        let nonce = 0u64;
         let new_name = ManagedBuffer::new_from_bytes(&b""[..]);
         let new_uri = ManagedBuffer::new_from_bytes(&b""[..]);
         let new_public_key = ManagedBuffer::new_from_bytes(&b""[..]);
@@ -215,12 +212,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(identity_registry_proxy::IdentityRegistryProxy)
-            .update_agent(new_name, new_uri, new_public_key, metadata, services)
-            .payment((
-                EsdtTokenIdentifier::from(token_id.as_str()),
-                token_nonce,
-                token_amount,
-            ))
+            .update_agent(nonce, new_name, new_uri, new_public_key, metadata, services)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -318,13 +310,17 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    pub async fn agents(&mut self) {
+    pub async fn get_agents_by_owner(&mut self) {
+        let owner = &self.wallet_address;
+        let from = 0usize;
+        let size = 10usize;
+
         let result_value = self
             .interactor
             .query()
             .to(self.state.current_address())
             .typed(identity_registry_proxy::IdentityRegistryProxy)
-            .agents()
+            .get_agents_by_owner(owner, from, size)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -1,7 +1,20 @@
 pub use common::errors::ERR_AGENT_NOT_FOUND;
+pub use common::errors::ERR_AGENT_PAUSED;
+pub use common::errors::ERR_INVALID_THRESHOLD;
 pub use common::errors::ERR_NOT_AGENT_OWNER as ERR_NOT_OWNER;
 
 pub const ERR_TOKEN_ALREADY_ISSUED: &str = "Token already issued";
 pub const ERR_TOKEN_NOT_ISSUED: &str = "Token not issued";
 pub const ERR_AGENT_ALREADY_REGISTERED: &str = "Agent already registered for this address";
-pub const ERR_INVALID_NFT: &str = "Invalid NFT sent";
+pub const ERR_TRANSFER_TO_SELF: &str = "Agent is already owned by this address";
+pub const ERR_NO_PENDING_TRANSFER: &str = "No pending transfer for this agent";
+pub const ERR_NOT_PENDING_OWNER: &str = "Only the proposed owner can accept the transfer";
+pub const ERR_AGENT_NOT_PAUSED: &str = "Agent is not paused";
+pub const ERR_INVALID_PERMISSIONS: &str = "Invalid operator permissions";
+pub const ERR_OPERATOR_IS_OWNER: &str = "Agent owner cannot be its own operator";
+pub const ERR_OPERATOR_NOT_FOUND: &str = "Operator not found";
+pub const ERR_SERVICE_NOT_FOUND: &str = "Service config not found for agent";
+pub const ERR_DUPLICATE_SERVICE_TOKEN: &str = "Token listed more than once for the same service";
+pub const ERR_NOT_AGENT_NFT: &str = "Payment must be an agent identity NFT";
+pub const ERR_NFT_NOT_IN_CUSTODY: &str =
+    "Agent NFT is held in a wallet; deposit it with custody_agent_nft first";
@@ -20,4 +20,50 @@ pub trait EventsModule {
 
     #[event("serviceConfigsUpdated")]
     fn service_configs_updated_event(&self, #[indexed] nonce: u64);
+
+    #[event("agentTransferProposed")]
+    fn agent_transfer_proposed_event(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );
+
+    #[event("agentTransferred")]
+    fn agent_transferred_event(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] previous_owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );
+
+    #[event("operatorSet")]
+    fn operator_set_event(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] operator: &ManagedAddress,
+        #[indexed] permissions: u8,
+    );
+
+    #[event("operatorRemoved")]
+    fn operator_removed_event(&self, #[indexed] nonce: u64, #[indexed] operator: &ManagedAddress);
+
+    #[event("agentPaused")]
+    fn agent_paused_event(&self, #[indexed] nonce: u64);
+
+    #[event("agentResumed")]
+    fn agent_resumed_event(&self, #[indexed] nonce: u64);
+
+    #[event("agentDeregistered")]
+    fn agent_deregistered_event(&self, #[indexed] nonce: u64, #[indexed] owner: &ManagedAddress);
+
+    #[event("agentNftCustodied")]
+    fn agent_nft_custodied_event(&self, #[indexed] nonce: u64, #[indexed] owner: &ManagedAddress);
+
+    #[event("agentTransferCancelled")]
+    fn agent_transfer_cancelled_event(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] owner: &ManagedAddress,
+    );
 }
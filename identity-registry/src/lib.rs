@@ -25,8 +25,14 @@ pub trait IdentityRegistry:
     #[init]
     fn init(&self) {}
 
+    /// `agent_nonces`: agents still in the legacy 1:1 owner map, migrated in batches so a
+    /// large registry never has to fit in one upgrade. Other nonces are skipped.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, agent_nonces: MultiValueEncoded<u64>) {
+        for nonce in agent_nonces {
+            self.migrate_legacy_agent(nonce);
+        }
+    }
 
     #[only_owner]
     #[payable("EGLD")]
@@ -45,7 +51,27 @@ pub trait IdentityRegistry:
         );
     }
 
+    /// Allow (or disallow) a single address to own several agents.
+    /// Disabling does not affect owners who already hold more than one agent.
+    #[only_owner]
+    #[endpoint(set_multi_agent_mode)]
+    fn set_multi_agent_mode(&self, enabled: bool) {
+        self.multi_agent_mode().set(enabled);
+    }
+
+    /// Move agents out of the legacy 1:1 owner map between upgrades, in as many batches as
+    /// needed. Until then cross-contract readers of `agentOwner` do not find them.
+    /// Unknown or already migrated nonces are skipped.
+    #[only_owner]
+    #[endpoint(migrate_legacy_agents)]
+    fn migrate_legacy_agents(&self, agent_nonces: MultiValueEncoded<u64>) {
+        for nonce in agent_nonces {
+            self.migrate_legacy_agent(nonce);
+        }
+    }
+
     /// Register a new agent with name, URI, public key, optional metadata, and optional service configs.
+    /// The identity NFT is minted into registry custody and never leaves it.
     #[allow_multiple_var_args]
     #[endpoint(register_agent)]
     fn register_agent(
@@ -59,17 +85,16 @@ pub trait IdentityRegistry:
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
 
         let caller = self.blockchain().get_caller();
-        require!(
-            !self.agents().contains_value(&caller),
-            ERR_AGENT_ALREADY_REGISTERED
-        );
+        self.require_can_own_agent(&caller);
 
         let details = AgentDetails {
             name: name.clone(),
             public_key,
         };
 
-        // Mint soulbound NFT — proof of agent identity
+        // Mint soulbound NFT — proof of agent identity.
+        // The NFT stays custodied by the registry: `agentOwner` is the single source of
+        // truth for ownership, so a wallet-level ESDT transfer can never detach it.
         let nonce = self.send().esdt_nft_create(
             &self.agent_token_id().get_token_id(),
             &BigUint::from(1u64),
@@ -81,7 +106,7 @@ pub trait IdentityRegistry:
         );
 
         // Store all data in storage mappers
-        self.agents().insert(nonce, caller.clone());
+        self.set_agent_owner(nonce, &caller);
         self.agent_details(nonce).set(&details);
 
         // Store metadata if provided
@@ -94,27 +119,18 @@ pub trait IdentityRegistry:
             nonce,
             AgentRegisteredEventData {
                 name: details.name,
-                uri: uri.clone(),
+                uri,
             },
         );
-
-        // Send NFT to caller
-        self.tx()
-            .to(&caller)
-            .single_esdt(
-                &self.agent_token_id().get_token_id(),
-                nonce,
-                &BigUint::from(1u64),
-            )
-            .transfer();
     }
 
-    /// Update an agent's URI and/or public_key. Requires sending the agent NFT.
-    #[payable("*")]
+    /// Update an agent's name, URI and public_key. The custodied NFT is recreated in place
+    /// and `agent_details` is kept in sync, so a new public key takes effect for signed proofs.
     #[allow_multiple_var_args]
     #[endpoint(update_agent)]
     fn update_agent(
         &self,
+        nonce: u64,
         new_name: ManagedBuffer,
         new_uri: ManagedBuffer,
         new_public_key: ManagedBuffer,
@@ -122,25 +138,25 @@ pub trait IdentityRegistry:
         services: OptionalValue<MultiValueEncodedCounted<ServiceConfigInput<Self::Api>>>,
     ) {
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+        self.require_agent_owner(nonce);
+        self.require_nft_in_custody(nonce);
 
-        let payment = self.call_value().single_esdt();
-        let token_id = self.agent_token_id().get_token_id();
-        require!(payment.token_identifier == token_id, ERR_INVALID_NFT);
-
-        let nonce = payment.token_nonce;
-        let caller = self.blockchain().get_caller();
-        let owner = self.agents().get_value(&nonce);
-        require!(caller == owner, ERR_NOT_OWNER);
+        let details = AgentDetails {
+            name: new_name,
+            public_key: new_public_key,
+        };
 
+        let token_id = self.agent_token_id().get_token_id();
         self.send().esdt_metadata_recreate(
-            token_id.clone(),
+            token_id,
             nonce,
-            new_name,
+            details.name.clone(),
             0,
-            new_public_key,
-            &ManagedBuffer::new(),
+            ManagedBuffer::new(),
+            &details,
             ManagedVec::from_single_item(new_uri),
         );
+        self.agent_details(nonce).set(&details);
 
         if let OptionalValue::Some(m) = metadata {
             self.sync_metadata(nonce, m);
@@ -149,16 +165,29 @@ pub trait IdentityRegistry:
             self.sync_service_configs(nonce, configs);
         }
 
-        // Send NFT back to caller
-        self.tx()
-            .to(&caller)
-            .single_esdt(&token_id, nonce, &BigUint::from(1u64))
-            .transfer();
-
         self.agent_updated_event(nonce);
     }
 
+    /// Hand over the identity NFT of an agent registered before NFTs were custodied, which
+    /// still sits in a wallet and can be transferred freely until then. Holding it grants
+    /// nothing: only the owner recorded in `agentOwner` can deposit it, whoever sent it.
+    /// Until it is deposited, `update_agent` and `deregister_agent` are rejected for that agent.
+    #[payable("*")]
+    #[endpoint(custody_agent_nft)]
+    fn custody_agent_nft(&self) {
+        require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.agent_token_id().get_token_id(),
+            ERR_NOT_AGENT_NFT
+        );
+        let caller = self.require_agent_owner(payment.token_nonce);
+
+        self.agent_nft_custodied_event(payment.token_nonce, &caller);
+    }
+
     /// Set or update metadata entries for an agent. O(1) per entry via MapMapper.
+    /// Callable by the owner or an operator with `PERMISSION_EDIT_METADATA`.
     #[endpoint(set_metadata)]
     fn set_metadata(
         &self,
@@ -166,12 +195,13 @@ pub trait IdentityRegistry:
         entries: MultiValueEncodedCounted<MetadataEntry<Self::Api>>,
     ) {
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
-        self.require_agent_owner(nonce);
+        self.require_agent_owner_or_operator(nonce, PERMISSION_EDIT_METADATA);
         self.sync_metadata(nonce, entries);
         self.metadata_updated_event(nonce);
     }
 
     /// Set or update service configurations for an agent.
+    /// Callable by the owner or an operator with `PERMISSION_EDIT_SERVICES`.
     #[endpoint(set_service_configs)]
     fn set_service_configs_endpoint(
         &self,
@@ -179,7 +209,7 @@ pub trait IdentityRegistry:
         configs: MultiValueEncodedCounted<ServiceConfigInput<Self::Api>>,
     ) {
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
-        self.require_agent_owner(nonce);
+        self.require_agent_owner_or_operator(nonce, PERMISSION_EDIT_SERVICES);
         self.sync_service_configs(nonce, configs);
         self.service_configs_updated_event(nonce);
     }
@@ -188,7 +218,7 @@ pub trait IdentityRegistry:
     #[endpoint(remove_metadata)]
     fn remove_metadata(&self, nonce: u64, keys: MultiValueEncoded<ManagedBuffer>) {
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
-        self.require_agent_owner(nonce);
+        self.require_agent_owner_or_operator(nonce, PERMISSION_EDIT_METADATA);
         let mut mapper = self.agent_metadata(nonce);
         for key in keys {
             mapper.remove(&key);
@@ -200,11 +230,183 @@ pub trait IdentityRegistry:
     #[endpoint(remove_service_configs)]
     fn remove_service_configs(&self, nonce: u64, service_ids: MultiValueEncoded<u32>) {
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
-        self.require_agent_owner(nonce);
+        self.require_agent_owner_or_operator(nonce, PERMISSION_EDIT_SERVICES);
         let mut mapper = self.agent_service_config(nonce);
+        let mut thresholds = self.agent_service_thresholds(nonce);
+        let mut pay_on_verification = self.agent_pay_on_verification_services(nonce);
         for sid in service_ids {
             mapper.remove(&sid);
+            thresholds.remove(&sid);
+            pay_on_verification.swap_remove(&sid);
         }
         self.service_configs_updated_event(nonce);
     }
+
+    /// Require validator responses of at least `threshold` (0-100) before jobs for `service_id`
+    /// count as verified. Validation-registry's global threshold still applies as a floor.
+    /// Callable by the owner or an operator with `PERMISSION_EDIT_SERVICES`.
+    #[endpoint(set_service_pass_threshold)]
+    fn set_service_pass_threshold(&self, nonce: u64, service_id: u32, threshold: u8) {
+        require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+        self.require_agent_owner_or_operator(nonce, PERMISSION_EDIT_SERVICES);
+        require!(
+            self.agent_service_config(nonce).contains_key(&service_id),
+            ERR_SERVICE_NOT_FOUND
+        );
+        require!(threshold <= MAX_VALIDATION_SCORE, ERR_INVALID_THRESHOLD);
+
+        self.agent_service_thresholds(nonce)
+            .insert(service_id, threshold);
+        self.service_configs_updated_event(nonce);
+    }
+
+    /// Have validation-registry hold payments for `service_id` until the job is verified,
+    /// instead of forwarding them to the owner when the job is opened.
+    /// Callable by the owner or an operator with `PERMISSION_EDIT_SERVICES`.
+    #[endpoint(set_service_pay_on_verification)]
+    fn set_service_pay_on_verification(&self, nonce: u64, service_id: u32, enabled: bool) {
+        require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+        self.require_agent_owner_or_operator(nonce, PERMISSION_EDIT_SERVICES);
+        require!(
+            self.agent_service_config(nonce).contains_key(&service_id),
+            ERR_SERVICE_NOT_FOUND
+        );
+
+        let mut services = self.agent_pay_on_verification_services(nonce);
+        if enabled {
+            services.insert(service_id);
+        } else {
+            services.swap_remove(&service_id);
+        }
+        self.service_configs_updated_event(nonce);
+    }
+
+    // ── Operators (delegated hot keys) ──
+
+    /// Grant `operator` the given permission bitmask on an agent, replacing any previous grant.
+    #[endpoint(set_agent_operator)]
+    fn set_agent_operator(&self, nonce: u64, operator: ManagedAddress, permissions: u8) {
+        let caller = self.require_agent_owner(nonce);
+        require!(operator != caller, ERR_OPERATOR_IS_OWNER);
+        require!(
+            permissions != 0 && permissions & !ALL_PERMISSIONS == 0,
+            ERR_INVALID_PERMISSIONS
+        );
+
+        self.agent_operators(nonce)
+            .insert(operator.clone(), permissions);
+        self.operator_set_event(nonce, &operator, permissions);
+    }
+
+    /// Revoke all permissions of `operator` on an agent.
+    #[endpoint(remove_agent_operator)]
+    fn remove_agent_operator(&self, nonce: u64, operator: ManagedAddress) {
+        self.require_agent_owner(nonce);
+        require!(
+            self.agent_operators(nonce).remove(&operator).is_some(),
+            ERR_OPERATOR_NOT_FOUND
+        );
+        self.operator_removed_event(nonce, &operator);
+    }
+
+    // ── Ownership transfer (two-step handover) ──
+
+    /// Propose handing an agent over to `new_owner`. Takes effect only once accepted.
+    /// A new proposal replaces any pending one.
+    #[endpoint(propose_agent_transfer)]
+    fn propose_agent_transfer(&self, nonce: u64, new_owner: ManagedAddress) {
+        require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+        let caller = self.require_agent_owner(nonce);
+        require!(new_owner != caller, ERR_TRANSFER_TO_SELF);
+
+        self.pending_agent_transfer(nonce).set(&new_owner);
+        self.agent_transfer_proposed_event(nonce, &caller, &new_owner);
+    }
+
+    /// Accept a pending transfer. Caller must be the proposed owner and, unless
+    /// multi-agent mode is on, must not own an agent yet. Operators of the previous owner are revoked.
+    /// The NFT stays custodied by the registry — only the recorded owner changes.
+    #[endpoint(accept_agent_transfer)]
+    fn accept_agent_transfer(&self, nonce: u64) {
+        let pending_mapper = self.pending_agent_transfer(nonce);
+        require!(!pending_mapper.is_empty(), ERR_NO_PENDING_TRANSFER);
+
+        let caller = self.blockchain().get_caller();
+        require!(caller == pending_mapper.get(), ERR_NOT_PENDING_OWNER);
+        self.require_can_own_agent(&caller);
+
+        let previous_owner = self.agent_owner(nonce).get();
+        self.set_agent_owner(nonce, &caller);
+        self.agent_operators(nonce).clear();
+        pending_mapper.clear();
+
+        self.agent_transferred_event(nonce, &previous_owner, &caller);
+    }
+
+    /// Cancel a pending transfer proposal.
+    #[endpoint(cancel_agent_transfer)]
+    fn cancel_agent_transfer(&self, nonce: u64) {
+        let caller = self.require_agent_owner(nonce);
+        let pending_mapper = self.pending_agent_transfer(nonce);
+        require!(!pending_mapper.is_empty(), ERR_NO_PENDING_TRANSFER);
+
+        pending_mapper.clear();
+        self.agent_transfer_cancelled_event(nonce, &caller);
+    }
+
+    // ── Status ──
+
+    /// Temporarily take an agent out of service. Identity, metadata and reputation are kept;
+    /// validation-registry refuses new jobs until the agent is resumed.
+    #[endpoint(pause_agent)]
+    fn pause_agent(&self, nonce: u64) {
+        self.require_agent_owner(nonce);
+        let status_mapper = self.agent_status(nonce);
+        require!(status_mapper.get() == AgentStatus::Active, ERR_AGENT_PAUSED);
+
+        status_mapper.set(AgentStatus::Paused);
+        self.agent_paused_event(nonce);
+    }
+
+    /// Put a paused agent back into service.
+    #[endpoint(resume_agent)]
+    fn resume_agent(&self, nonce: u64) {
+        self.require_agent_owner(nonce);
+        let status_mapper = self.agent_status(nonce);
+        require!(
+            status_mapper.get() == AgentStatus::Paused,
+            ERR_AGENT_NOT_PAUSED
+        );
+
+        status_mapper.set(AgentStatus::Active);
+        self.agent_resumed_event(nonce);
+    }
+
+    // ── Deregistration ──
+
+    /// Retire an agent: burn the custodied identity NFT and clear all of its storage.
+    /// The nonce is never reused, so cross-contract readers see the agent as not found.
+    #[endpoint(deregister_agent)]
+    fn deregister_agent(&self, nonce: u64) {
+        require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+        let caller = self.require_agent_owner(nonce);
+        self.require_nft_in_custody(nonce);
+
+        let token_id = self.agent_token_id().get_token_id();
+        self.send()
+            .esdt_local_burn(&token_id, nonce, &BigUint::from(1u64));
+
+        self.owner_agents(&caller).swap_remove(&nonce);
+        self.agent_owner(nonce).clear();
+        self.agent_details(nonce).clear();
+        self.agent_metadata(nonce).clear();
+        self.agent_service_config(nonce).clear();
+        self.agent_service_thresholds(nonce).clear();
+        self.agent_pay_on_verification_services(nonce).clear();
+        self.agent_status(nonce).clear();
+        self.agent_operators(nonce).clear();
+        self.pending_agent_transfer(nonce).clear();
+
+        self.agent_deregistered_event(nonce, &caller);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::AgentDetails;
+use crate::{AgentDetails, AgentStatus};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -9,9 +9,20 @@ pub trait StorageModule {
     #[storage_mapper("agentTokenId")]
     fn agent_token_id(&self) -> NonFungibleTokenMapper;
 
-    #[view(get_agent_id)]
+    #[storage_mapper("agentOwner")]
+    fn agent_owner(&self, nonce: u64) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("ownerAgents")]
+    fn owner_agents(&self, owner: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[view(is_multi_agent_mode)]
+    #[storage_mapper("multiAgentMode")]
+    fn multi_agent_mode(&self) -> SingleValueMapper<bool>;
+
+    /// 1:1 owner mapping used before multi-agent ownership. Drained in batches by `upgrade`
+    /// and `migrate_legacy_agents`.
     #[storage_mapper("agents")]
-    fn agents(&self) -> BiDiMapper<u64, ManagedAddress<Self::Api>>;
+    fn legacy_agents(&self) -> BiDiMapper<u64, ManagedAddress<Self::Api>>;
 
     #[view(get_agent_details)]
     #[storage_mapper("agentDetails")]
@@ -21,7 +32,29 @@ pub trait StorageModule {
     #[storage_mapper("agentMetadatas")]
     fn agent_metadata(&self, nonce: u64) -> MapMapper<ManagedBuffer, ManagedBuffer>;
 
+    /// Service ID -> payments the service accepts, any one of which pays for a job.
+    /// A single legacy `Payment` decodes as a one-entry list.
     #[view(get_agent_service)]
     #[storage_mapper("agentServiceConfigs")]
-    fn agent_service_config(&self, nonce: u64) -> MapMapper<u32, Payment<Self::Api>>;
+    fn agent_service_config(&self, nonce: u64) -> MapMapper<u32, ManagedVec<Payment<Self::Api>>>;
+
+    /// Service ID -> minimum validation score for jobs of that service.
+    /// Validation-registry applies the stricter of this and its global threshold.
+    #[storage_mapper("agentServiceThresholds")]
+    fn agent_service_thresholds(&self, nonce: u64) -> MapMapper<u32, u8>;
+
+    /// Services whose payments validation-registry holds until the job is verified.
+    #[storage_mapper("agentPayOnVerificationServices")]
+    fn agent_pay_on_verification_services(&self, nonce: u64) -> UnorderedSetMapper<u32>;
+
+    #[view(get_agent_status)]
+    #[storage_mapper("agentStatus")]
+    fn agent_status(&self, nonce: u64) -> SingleValueMapper<AgentStatus>;
+
+    /// Delegated operator -> permission bitmask (see `common::structs::PERMISSION_*`).
+    #[storage_mapper("agentOperators")]
+    fn agent_operators(&self, nonce: u64) -> MapMapper<ManagedAddress, u8>;
+
+    #[storage_mapper("pendingAgentTransfer")]
+    fn pending_agent_transfer(&self, nonce: u64) -> SingleValueMapper<ManagedAddress>;
 }
@@ -6,12 +6,70 @@ multiversx_sc::imports!();
 #[multiversx_sc::module]
 pub trait UtilsModule: crate::storage::StorageModule {
     fn require_agent_owner(&self, nonce: u64) -> ManagedAddress {
+        let owner_mapper = self.agent_owner(nonce);
+        require!(!owner_mapper.is_empty(), ERR_AGENT_NOT_FOUND);
         let caller = self.blockchain().get_caller();
-        let owner = self.agents().get_value(&nonce);
-        require!(caller == owner, ERR_NOT_OWNER);
+        require!(caller == owner_mapper.get(), ERR_NOT_OWNER);
         caller
     }
 
+    /// Owner, or an operator holding every bit of `permission`.
+    fn require_agent_owner_or_operator(&self, nonce: u64, permission: u8) -> ManagedAddress {
+        let owner_mapper = self.agent_owner(nonce);
+        require!(!owner_mapper.is_empty(), ERR_AGENT_NOT_FOUND);
+        let caller = self.blockchain().get_caller();
+        if caller != owner_mapper.get() {
+            let granted = self.agent_operators(nonce).get(&caller).unwrap_or_default();
+            require!(granted & permission == permission, ERR_NOT_OWNER);
+        }
+        caller
+    }
+
+    /// Agents registered before NFTs were custodied may still hold theirs in a wallet,
+    /// where the registry can neither recreate nor burn it.
+    fn require_nft_in_custody(&self, nonce: u64) {
+        let token_id = self.agent_token_id().get_token_id();
+        let balance = self.blockchain().get_esdt_balance(
+            &self.blockchain().get_sc_address(),
+            &token_id,
+            nonce,
+        );
+        require!(balance > 0u64, ERR_NFT_NOT_IN_CUSTODY);
+    }
+
+    /// Outside multi-agent mode an address may own at most one agent, counting one still
+    /// waiting in the legacy owner map.
+    fn require_can_own_agent(&self, address: &ManagedAddress) {
+        if !self.multi_agent_mode().get() {
+            require!(
+                self.owner_agents(address).is_empty()
+                    && !self.legacy_agents().contains_value(address),
+                ERR_AGENT_ALREADY_REGISTERED
+            );
+        }
+    }
+
+    fn set_agent_owner(&self, nonce: u64, owner: &ManagedAddress) {
+        let owner_mapper = self.agent_owner(nonce);
+        if !owner_mapper.is_empty() {
+            self.owner_agents(&owner_mapper.get()).swap_remove(&nonce);
+        }
+        owner_mapper.set(owner);
+        self.owner_agents(owner).insert(nonce);
+    }
+
+    /// Move `nonce` out of the legacy 1:1 owner map. Nonces not in it (never registered
+    /// before the upgrade, or already migrated) are skipped.
+    fn migrate_legacy_agent(&self, nonce: u64) {
+        let mut legacy = self.legacy_agents();
+        if !legacy.contains_id(&nonce) {
+            return;
+        }
+        let owner = legacy.get_value(&nonce);
+        legacy.remove_by_id(&nonce);
+        self.set_agent_owner(nonce, &owner);
+    }
+
     fn sync_metadata(
         &self,
         nonce: u64,
@@ -27,11 +85,29 @@ pub trait UtilsModule: crate::storage::StorageModule {
         nonce: u64,
         configs: MultiValueEncodedCounted<ServiceConfigInput<Self::Api>>,
     ) {
+        let mut mapper = self.agent_service_config(nonce);
+        let mut updated_services = ManagedVec::<Self::Api, u32>::new();
         for config in configs {
             let amount = NonZeroBigUint::new_or_panic(config.price);
             let payment = Payment::new(config.token, config.nonce, amount);
-            self.agent_service_config(nonce)
-                .insert(config.service_id, payment);
+
+            // The first entry for a service replaces its accepted payments,
+            // later entries in the same call add alternatives
+            let mut accepted = if updated_services.contains(&config.service_id) {
+                mapper.get(&config.service_id).unwrap_or_default()
+            } else {
+                updated_services.push(config.service_id);
+                ManagedVec::new()
+            };
+            require!(
+                !accepted
+                    .iter()
+                    .any(|p| p.token_identifier == payment.token_identifier
+                        && p.token_nonce == payment.token_nonce),
+                ERR_DUPLICATE_SERVICE_TOKEN
+            );
+            accepted.push(payment);
+            mapper.insert(config.service_id, accepted);
         }
     }
 }
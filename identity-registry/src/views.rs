@@ -12,7 +12,59 @@ pub trait ViewsModule: crate::storage::StorageModule {
 
     #[view(get_agent_owner)]
     fn get_agent_owner(&self, nonce: u64) -> ManagedAddress {
-        self.agents().get_value(&nonce)
+        self.agent_owner(nonce).get()
+    }
+
+    /// Agent nonces owned by `owner`, paginated. `from` is 0-based; order is not stable across removals.
+    #[view(get_agents_by_owner)]
+    fn get_agents_by_owner(
+        &self,
+        owner: ManagedAddress,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<u64> {
+        let mapper = self.owner_agents(&owner);
+        let end = core::cmp::min(from.saturating_add(size), mapper.len());
+        let mut result = MultiValueEncoded::new();
+        for index in from..end {
+            result.push(mapper.get_by_index(index + 1));
+        }
+        result
+    }
+
+    #[view(get_agent_count_by_owner)]
+    fn get_agent_count_by_owner(&self, owner: ManagedAddress) -> usize {
+        self.owner_agents(&owner).len()
+    }
+
+    #[view(get_pending_agent_transfer)]
+    fn get_pending_agent_transfer(&self, nonce: u64) -> OptionalValue<ManagedAddress> {
+        let mapper = self.pending_agent_transfer(nonce);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    /// Permission bitmask of `operator` on an agent; 0 if it is not an operator.
+    #[view(get_operator_permissions)]
+    fn get_operator_permissions(&self, nonce: u64, operator: ManagedAddress) -> u8 {
+        self.agent_operators(nonce)
+            .get(&operator)
+            .unwrap_or_default()
+    }
+
+    #[view(get_agent_operators)]
+    fn get_agent_operators(
+        &self,
+        nonce: u64,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, u8>> {
+        let mut result = MultiValueEncoded::new();
+        for (operator, permissions) in self.agent_operators(nonce).iter() {
+            result.push((operator, permissions).into());
+        }
+        result
     }
 
     #[view(get_metadata)]
@@ -25,6 +77,7 @@ pub trait ViewsModule: crate::storage::StorageModule {
         }
     }
 
+    /// First accepted payment of a service. See `get_agent_service_payments` for all of them.
     #[view(get_agent_service_config)]
     fn get_agent_service_config(
         &self,
@@ -32,12 +85,48 @@ pub trait ViewsModule: crate::storage::StorageModule {
         service_id: u32,
     ) -> OptionalValue<EgldOrEsdtTokenPayment<Self::Api>> {
         let mapper = self.agent_service_config(nonce);
-        if let Some(payment) = mapper.get(&service_id) {
-            OptionalValue::Some(EgldOrEsdtTokenPayment::new(
-                EgldOrEsdtTokenIdentifier::from(payment.token_identifier),
-                payment.token_nonce,
-                payment.amount.into_big_uint(),
-            ))
+        match mapper.get(&service_id) {
+            Some(accepted) if !accepted.is_empty() => {
+                let payment = accepted.get(0);
+                OptionalValue::Some(EgldOrEsdtTokenPayment::new(
+                    EgldOrEsdtTokenIdentifier::from(payment.token_identifier.clone()),
+                    payment.token_nonce,
+                    payment.amount.as_big_uint().clone(),
+                ))
+            }
+            _ => OptionalValue::None,
+        }
+    }
+
+    #[view(get_agent_service_payments)]
+    fn get_agent_service_payments(
+        &self,
+        nonce: u64,
+        service_id: u32,
+    ) -> MultiValueEncoded<EgldOrEsdtTokenPayment<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        if let Some(accepted) = self.agent_service_config(nonce).get(&service_id) {
+            for payment in accepted.iter() {
+                result.push(EgldOrEsdtTokenPayment::new(
+                    EgldOrEsdtTokenIdentifier::from(payment.token_identifier.clone()),
+                    payment.token_nonce,
+                    payment.amount.as_big_uint().clone(),
+                ));
+            }
+        }
+        result
+    }
+
+    #[view(is_service_pay_on_verification)]
+    fn is_service_pay_on_verification(&self, nonce: u64, service_id: u32) -> bool {
+        self.agent_pay_on_verification_services(nonce)
+            .contains(&service_id)
+    }
+
+    #[view(get_service_pass_threshold)]
+    fn get_service_pass_threshold(&self, nonce: u64, service_id: u32) -> OptionalValue<u8> {
+        if let Some(threshold) = self.agent_service_thresholds(nonce).get(&service_id) {
+            OptionalValue::Some(threshold)
         } else {
             OptionalValue::None
         }
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           16
+// Endpoints:                           38
 // Async Callback:                       1
-// Total number of exported functions:  19
+// Total number of exported functions:  41
 
 #![no_std]
 
@@ -21,21 +21,43 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         issue_token => issue_token
+        set_multi_agent_mode => set_multi_agent_mode
+        migrate_legacy_agents => migrate_legacy_agents
         register_agent => register_agent
         update_agent => update_agent
+        custody_agent_nft => custody_agent_nft
         set_metadata => set_metadata
         set_service_configs => set_service_configs_endpoint
         remove_metadata => remove_metadata
         remove_service_configs => remove_service_configs
+        set_service_pass_threshold => set_service_pass_threshold
+        set_service_pay_on_verification => set_service_pay_on_verification
+        set_agent_operator => set_agent_operator
+        remove_agent_operator => remove_agent_operator
+        propose_agent_transfer => propose_agent_transfer
+        accept_agent_transfer => accept_agent_transfer
+        cancel_agent_transfer => cancel_agent_transfer
+        pause_agent => pause_agent
+        resume_agent => resume_agent
+        deregister_agent => deregister_agent
         get_agent_token_id => agent_token_id
-        get_agent_id => agents
+        is_multi_agent_mode => multi_agent_mode
         get_agent_details => agent_details
         get_agent_metadata => agent_metadata
         get_agent_service => agent_service_config
+        get_agent_status => agent_status
         get_agent => get_agent
         get_agent_owner => get_agent_owner
+        get_agents_by_owner => get_agents_by_owner
+        get_agent_count_by_owner => get_agent_count_by_owner
+        get_pending_agent_transfer => get_pending_agent_transfer
+        get_operator_permissions => get_operator_permissions
+        get_agent_operators => get_agent_operators
         get_metadata => get_metadata
         get_agent_service_config => get_agent_service_config
+        get_agent_service_payments => get_agent_service_payments
+        is_service_pay_on_verification => is_service_pay_on_verification
+        get_service_pass_threshold => get_service_pass_threshold
     )
 }
 
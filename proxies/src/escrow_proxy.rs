@@ -69,12 +69,16 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn upgrade(
+    pub fn upgrade<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
         self,
+        job_ids: Arg0,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
+            .argument(&job_ids)
             .original_result()
     }
 }
@@ -109,6 +113,30 @@ where
             .original_result()
     }
 
+    pub fn deposit_milestones<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<BigUint<Env::Api>, ManagedBuffer<Env::Api>>>>,
+    >(
+        self,
+        job_id: Arg0,
+        receiver: Arg1,
+        poa_hash: Arg2,
+        deadline: Arg3,
+        milestones: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit_milestones")
+            .argument(&job_id)
+            .argument(&receiver)
+            .argument(&poa_hash)
+            .argument(&deadline)
+            .argument(&milestones)
+            .original_result()
+    }
+
     pub fn release<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
@@ -122,6 +150,22 @@ where
             .original_result()
     }
 
+    pub fn release_milestone<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        job_id: Arg0,
+        milestone_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("release_milestone")
+            .argument(&job_id)
+            .argument(&milestone_index)
+            .original_result()
+    }
+
     pub fn refund<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
@@ -148,6 +192,24 @@ where
             .original_result()
     }
 
+    pub fn get_arbiters(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_arbiters")
+            .original_result()
+    }
+
+    pub fn get_dispute_timeout(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_dispute_timeout")
+            .original_result()
+    }
+
     pub fn get_validation_contract_address(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
@@ -165,4 +227,188 @@ where
             .raw_call("get_identity_contract_address")
             .original_result()
     }
+
+    pub fn get_escrow_remaining<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_escrow_remaining")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_milestone<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        job_id: Arg0,
+        milestone_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<escrow::storage::Milestone<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_milestone")
+            .argument(&job_id)
+            .argument(&milestone_index)
+            .original_result()
+    }
+
+    pub fn get_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<escrow::storage::DisputeData<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_dispute")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn add_arbiter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        arbiter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("add_arbiter")
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn remove_arbiter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        arbiter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_arbiter")
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn set_dispute_timeout<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        timeout: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_dispute_timeout")
+            .argument(&timeout)
+            .original_result()
+    }
+
+    pub fn open_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("open_dispute")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn resolve_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        receiver_amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolve_dispute")
+            .argument(&job_id)
+            .argument(&receiver_amount)
+            .original_result()
+    }
+
+    pub fn resolve_expired_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolve_expired_dispute")
+            .argument(&job_id)
+            .original_result()
+    }
+    pub fn set_protocol_fee<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_protocol_fee")
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    pub fn set_treasury<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        treasury: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_treasury")
+            .argument(&treasury)
+            .original_result()
+    }
+
+    pub fn claim_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_fees")
+            .original_result()
+    }
+
+    pub fn get_accumulated_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Payment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_accumulated_fees")
+            .original_result()
+    }
+
+    pub fn get_protocol_fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_protocol_fee_bps")
+            .original_result()
+    }
+
+    pub fn get_treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_treasury")
+            .original_result()
+    }
 }
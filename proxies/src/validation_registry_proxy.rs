@@ -66,12 +66,18 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn upgrade(
+    /// `agent_nonces`: agents whose validation records predate stored request/response URIs. 
+    /// Their records are re-encoded with empty URIs; already migrated records are skipped. 
+    pub fn upgrade<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
         self,
+        agent_nonces: Arg0,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
+            .argument(&agent_nonces)
             .original_result()
     }
 }
@@ -103,6 +109,24 @@ where
             .original_result()
     }
 
+    /// Cancel a job the agent has not started (no proof submitted yet). Only the employer can 
+    /// call this. A held service payment is refunded; one already forwarded by `init_job` is 
+    /// not. Funds deposited in the escrow contract against this job become refundable right away. 
+    pub fn cancel_job<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_job")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Submit (or replace) the proof for a job. 
+    /// Caller must be the agent owner or an operator with `PERMISSION_SUBMIT_PROOF`. 
     pub fn submit_proof<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -119,50 +143,110 @@ where
             .original_result()
     }
 
-    /// NFT-holder variant: proves ownership by sending the agent NFT. 
-    /// The contract verifies token ID + nonce, executes proof, and returns the NFT. 
-    pub fn submit_proof_with_nft<
+    /// Relayed proof submission: anyone may submit `proof` on the agent's behalf, as long as 
+    /// `signature` is an Ed25519 signature over `signed_proof_message(job_id, proof)` by the 
+    /// agent's registered `public_key`. Lets agents operate without an on-chain wallet. 
+    /// The message covers the job's proof nonce, so a signature stops verifying once any 
+    /// proof has been stored after it was made. 
+    pub fn submit_signed_proof<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         job_id: Arg0,
         proof: Arg1,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        signature: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("submit_proof_with_nft")
+            .payment(NotPayable)
+            .raw_call("submit_signed_proof")
             .argument(&job_id)
             .argument(&proof)
+            .argument(&signature)
+            .original_result()
+    }
+
+    /// Split a job into deliverables validated one after the other, for milestone escrows. 
+    /// Only the employer can call this, before the first proof. Afterwards a request that 
+    /// reaches its quorum only records whether its deliverable passed; the job returns to 
+    /// `Pending` for the next proof and is never verified or rejected. 
+    pub fn enable_job_milestones<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enable_job_milestones")
+            .argument(&job_id)
             .original_result()
     }
 
     /// ERC-8004: Agent requests validation from a specific validator. 
-    /// MUST be called by the owner of the agent (agentId). 
+    /// MUST be called by the owner of the agent (agentId) or an operator with `PERMISSION_REQUEST_VALIDATION`, 
+    /// neither of whom can be the validator. An optional payment is escrowed as the validator's fee, paid out on its first response. 
+    /// `request_hash` must not have been used before. When `request_content` (the document 
+    /// behind `request_uri`) is supplied, `request_hash` must be its keccak256. 
     pub fn validation_request<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
         Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
     >(
         self,
         job_id: Arg0,
         validator_address: Arg1,
         request_uri: Arg2,
         request_hash: Arg3,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        request_content: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
             .raw_call("validation_request")
             .argument(&job_id)
             .argument(&validator_address)
             .argument(&request_uri)
             .argument(&request_hash)
+            .argument(&request_content)
+            .original_result()
+    }
+
+    /// Request validation from several validators at once. The job outcome is decided once 
+    /// `quorum` of them have responded, using the median of their responses. 
+    /// Same access rules as `validation_request`; an attached fee is split evenly between the validators. 
+    pub fn quorum_validation_request<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<u32>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        job_id: Arg0,
+        request_uri: Arg1,
+        request_hash: Arg2,
+        quorum: Arg3,
+        validators: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("quorum_validation_request")
+            .argument(&job_id)
+            .argument(&request_uri)
+            .argument(&request_hash)
+            .argument(&quorum)
+            .argument(&validators)
             .original_result()
     }
 
     /// ERC-8004: Validator responds with a result (0-100). 
-    /// MUST be called by the validatorAddress from the original request. 
-    /// Can be called multiple times for progressive validation. 
+    /// MUST be called by a validator nominated in the original request; each validator's 
+    /// latest response is kept, and `response_uri` is stored on the request as the latest 
+    /// evidence link. Once `quorum` validators have responded, the job is decided: 
+    /// `Verified` if the median response is at or above the job's pass threshold, `Rejected` 
+    /// below it. A held service payment then goes to the agent owner or back to the employer. 
+    /// Later (progressive) responses update the record only. 
     pub fn validation_response<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<u8>,
@@ -173,7 +257,7 @@ where
         self,
         request_hash: Arg0,
         response: Arg1,
-        _response_uri: Arg2,
+        response_uri: Arg2,
         response_hash: Arg3,
         tag: Arg4,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -182,12 +266,84 @@ where
             .raw_call("validation_response")
             .argument(&request_hash)
             .argument(&response)
-            .argument(&_response_uri)
+            .argument(&response_uri)
             .argument(&response_hash)
             .argument(&tag)
             .original_result()
     }
 
+    /// Close a request whose deadline passed without reaching its quorum. Anyone can call this. 
+    /// If it was the job's last open request, the job rolls back to `Pending` so the agent can 
+    /// request validation again (and may replace the proof). 
+    pub fn expire_validation_request<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("expire_validation_request")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    /// Replace a nominated validator that let the deadline pass without responding. 
+    /// The request gets a fresh deadline and the new validator takes over the fee share. 
+    /// Same access rules as `validation_request`, including who can be nominated. 
+    pub fn renominate_validator<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+        previous_validator: Arg1,
+        new_validator: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("renominate_validator")
+            .argument(&request_hash)
+            .argument(&previous_validator)
+            .argument(&new_validator)
+            .original_result()
+    }
+
+    /// Return the unpaid part of a request's validator fee to whoever paid it, once the 
+    /// fee timeout has passed. Anyone can call this. 
+    pub fn refund_validation_fee<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refund_validation_fee")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    /// Refund a held service payment to the employer once the hold has run out without the 
+    /// job being verified. Anyone can call this. The job is closed as `Expired`, so a late 
+    /// passing response can no longer verify it. 
+    pub fn refund_held_payment<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refund_held_payment")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Remove jobs older than 3 days. Anyone can call this. Jobs still under way or backing 
+    /// funds are skipped. Request records and the validation history stay: escrows read 
+    /// them to release milestones, and request hashes are never reused. 
     pub fn clean_old_jobs<
         Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
     >(
@@ -201,6 +357,148 @@ where
             .original_result()
     }
 
+    /// Set by `enable_job_milestones`: each validation request decides one deliverable 
+    /// (see `requestPassed`) and the job goes back to `Pending` instead of being finalised. 
+    pub fn job_milestones<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_milestone_job")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Proofs stored so far for the job, signed into `submit_signed_proof` messages so an 
+    /// earlier signature cannot be replayed. Kept when the job is cleaned. 
+    pub fn job_proof_nonce<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_proof_nonce")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn payment_hold_timeout(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_payment_hold_timeout")
+            .original_result()
+    }
+
+    /// Escrow contract whose active escrows keep their jobs from being cleaned. Unset 
+    /// until the owner configures it. 
+    pub fn escrow_contract_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_escrow_contract_address")
+            .original_result()
+    }
+
+    /// Minimum response for a job to be `Verified`; lower responses reject it. 
+    pub fn pass_threshold(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_pass_threshold")
+            .original_result()
+    }
+
+    /// Decided once, when the request first reaches its quorum: whether its aggregate response 
+    /// met the job's pass threshold. Empty while undecided. Read by escrow for milestones. 
+    pub fn request_passed<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_request_passed")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    pub fn validation_request_timeout(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validation_request_timeout")
+            .original_result()
+    }
+
+    /// Time after a request's deadline reserved for `renominate_validator`; only then can 
+    /// anyone expire the request. 
+    pub fn expiry_grace_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_expiry_grace_period")
+            .original_result()
+    }
+
+    /// How long validators have to answer before an escrowed fee becomes refundable. 
+    pub fn validator_fee_timeout(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validator_fee_timeout")
+            .original_result()
+    }
+
+    pub fn validator_stake_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validator_stake_token")
+            .original_result()
+    }
+
+    pub fn min_validator_stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_min_validator_stake")
+            .original_result()
+    }
+
+    pub fn validator_unbonding_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validator_unbonding_period")
+            .original_result()
+    }
+
+    /// When set, only active validators staked at least `minValidatorStake` can be nominated. 
+    pub fn require_staked_validators(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_staked_validator_required")
+            .original_result()
+    }
+
     pub fn is_job_verified<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
@@ -227,6 +525,20 @@ where
             .original_result()
     }
 
+    /// Service payment held for a pay-on-verification job, if not settled yet. 
+    pub fn get_held_payment<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<HeldPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_held_payment")
+            .argument(&job_id)
+            .original_result()
+    }
+
     /// ERC-8004: Returns validation status for a request hash. 
     pub fn get_validation_status<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -255,6 +567,129 @@ where
             .original_result()
     }
 
+    /// Request hashes opened on a job, oldest first. 
+    pub fn get_job_validation_history<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_validation_history")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Validators nominated for a request. 
+    pub fn get_request_validators<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_request_validators")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    /// Latest response of one validator to a request. 
+    pub fn get_validator_response<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+        validator: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ValidatorResponse<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validator_response")
+            .argument(&request_hash)
+            .argument(&validator)
+            .original_result()
+    }
+
+    /// Quorum progress and median response of a request. 
+    pub fn get_validation_outcome<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ValidationOutcome>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validation_outcome")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    /// Validator fee still escrowed for a request (absent once fully paid out or refunded). 
+    pub fn get_validation_fee<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        request_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ValidationFee<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validation_fee")
+            .argument(&request_hash)
+            .original_result()
+    }
+
+    pub fn get_validator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        validator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ValidatorInfo<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validator")
+            .argument(&validator)
+            .original_result()
+    }
+
+    /// Whether `validator` is active and staked at least the current minimum. 
+    pub fn is_validator_eligible<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        validator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_validator_eligible")
+            .argument(&validator)
+            .original_result()
+    }
+
+    pub fn get_registered_validators(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_registered_validators")
+            .original_result()
+    }
+
+    /// Registered validators declaring support for `tag`. 
+    pub fn get_validators_by_tag<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        tag: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_validators_by_tag")
+            .argument(&tag)
+            .original_result()
+    }
+
     pub fn set_identity_registry_address<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -267,4 +702,447 @@ where
             .argument(&address)
             .original_result()
     }
+
+    /// Escrow contract consulted by `clean_old_jobs`, so jobs with funds still in escrow 
+    /// are not cleaned. 
+    pub fn set_escrow_contract_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_escrow_contract_address")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Deadline given to validators on new requests (and on re-nomination). 
+    pub fn set_validation_request_timeout<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        timeout: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_validation_request_timeout")
+            .argument(&timeout)
+            .original_result()
+    }
+
+    /// How long after a request's deadline only the agent may act (by renominating), 
+    /// before anyone can expire the request. 
+    pub fn set_expiry_grace_period<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        grace_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_expiry_grace_period")
+            .argument(&grace_period)
+            .original_result()
+    }
+
+    /// How long validators have to answer before an escrowed fee becomes refundable. 
+    /// Applies to requests opened afterwards. 
+    pub fn set_validator_fee_timeout<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        timeout: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_validator_fee_timeout")
+            .argument(&timeout)
+            .original_result()
+    }
+
+    /// How long a held service payment waits for verification before the employer can 
+    /// reclaim it. Applies to jobs opened afterwards. 
+    pub fn set_payment_hold_timeout<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        timeout: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_payment_hold_timeout")
+            .argument(&timeout)
+            .original_result()
+    }
+
+    /// Global pass threshold (0-100). Agents may only raise it per service. 
+    pub fn set_pass_threshold<
+        Arg0: ProxyArg<u8>,
+    >(
+        self,
+        threshold: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_pass_threshold")
+            .argument(&threshold)
+            .original_result()
+    }
+
+    /// Configure validator bonding. Existing stakes keep the token they were bonded in. 
+    pub fn set_validator_staking<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        stake_token: Arg0,
+        min_stake: Arg1,
+        unbonding_period: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_validator_staking")
+            .argument(&stake_token)
+            .argument(&min_stake)
+            .argument(&unbonding_period)
+            .original_result()
+    }
+
+    /// Require every validator nominated in `validation_request` to be eligible 
+    /// (active and staked at least the minimum). 
+    pub fn set_require_staked_validators<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        required: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_require_staked_validators")
+            .argument(&required)
+            .original_result()
+    }
+
+    /// Register the caller as a validator, bonding the attached payment as stake. 
+    pub fn register_validator<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        tags: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("register_validator")
+            .argument(&tags)
+            .original_result()
+    }
+
+    /// Top up the caller's stake, in the token it was bonded in. 
+    pub fn add_validator_stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("add_validator_stake")
+            .original_result()
+    }
+
+    /// Replace the tags (validation domains) the caller declares to support. 
+    pub fn set_validator_tags<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        tags: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_validator_tags")
+            .argument(&tags)
+            .original_result()
+    }
+
+    /// Stop accepting nominations and start the unbonding period. 
+    pub fn unbond_validator(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unbond_validator")
+            .original_result()
+    }
+
+    /// Withdraw the remaining stake once unbonding is over. Removes the validator. 
+    pub fn withdraw_validator_stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw_validator_stake")
+            .original_result()
+    }
+
+    /// Governance: slash `amount` of a validator's stake for its response to `request_hash`. 
+    /// Slashed funds go to the contract owner. 
+    pub fn slash_validator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        validator: Arg0,
+        request_hash: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("slash_validator")
+            .argument(&validator)
+            .argument(&request_hash)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_protocol_fee<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_protocol_fee")
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    pub fn set_treasury<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        treasury: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_treasury")
+            .argument(&treasury)
+            .original_result()
+    }
+
+    /// Send every accumulated fee to the treasury. Callable by the owner or the treasury. 
+    pub fn claim_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim_fees")
+            .original_result()
+    }
+
+    pub fn get_accumulated_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Payment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_accumulated_fees")
+            .original_result()
+    }
+
+    pub fn protocol_fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_protocol_fee_bps")
+            .original_result()
+    }
+
+    pub fn treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_treasury")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct HeldPayment<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub payments: ManagedVec<Api, Payment<Api>>,
+    pub deadline: TimestampSeconds,
+    pub protocol_fee_bps: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ValidatorResponse<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub response: u8,
+    pub response_hash: ManagedBuffer<Api>,
+    pub tag: ManagedBuffer<Api>,
+    pub last_update: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ValidationOutcome {
+    pub quorum: u32,
+    pub validator_count: u32,
+    pub response_count: u32,
+    pub aggregate_response: u8,
+    pub quorum_reached: bool,
+    pub deadline: TimestampSeconds,
+    pub expired: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ValidationFee<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub payer: ManagedAddress<Api>,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub token_nonce: u64,
+    pub fee_per_validator: BigUint<Api>,
+    pub remaining: BigUint<Api>,
+    pub deadline: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ValidatorInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub stake_token: EgldOrEsdtTokenIdentifier<Api>,
+    pub stake_nonce: u64,
+    pub stake: BigUint<Api>,
+    pub tags: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub status: ValidatorStatus,
+    pub unbond_timestamp: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ValidatorStatus {
+    Active,
+    Unbonding,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidationRequestEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub job_id: ManagedBuffer<Api>,
+    pub request_uri: ManagedBuffer<Api>,
+    pub quorum: u32,
+    pub deadline: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobStatusChangedEventData {
+    pub agent_nonce: u64,
+    pub timestamp: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobCancelledEventData {
+    pub agent_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct HeldPaymentEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub agent_nonce: u64,
+    pub payments: ManagedVec<Api, Payment<Api>>,
+    pub deadline: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidationResponseEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub job_id: ManagedBuffer<Api>,
+    pub response: u8,
+    pub response_uri: ManagedBuffer<Api>,
+    pub response_hash: ManagedBuffer<Api>,
+    pub tag: ManagedBuffer<Api>,
+    pub aggregate_response: u8,
+    pub quorum_reached: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidationRequestExpiredEventData {
+    pub quorum: u32,
+    pub response_count: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorRenominatedEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub job_id: ManagedBuffer<Api>,
+    pub deadline: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorFeeEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub token_nonce: u64,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorStakeEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_identifier: EgldOrEsdtTokenIdentifier<Api>,
+    pub token_nonce: u64,
+    pub amount: BigUint<Api>,
+    pub total_stake: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorTagsEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub tags: ManagedVec<Api, ManagedBuffer<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorUnbondingEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub unbond_timestamp: TimestampSeconds,
+    pub stake: BigUint<Api>,
 }
@@ -86,16 +86,14 @@ pub trait ReputationRegistry:
     ) {
         let caller = self.blockchain().get_caller();
 
-        // 1. Caller MUST NOT be the agent owner
+        // 1. Agent MUST exist and caller MUST NOT be its owner
         let identity_addr = self.identity_contract_address().get();
-        let agent_mapper = self.external_agents(identity_addr);
-        let opt_owner_nonce = agent_mapper.get_id(&caller);
-        if opt_owner_nonce != 0 {
-            require!(
-                opt_owner_nonce != agent_nonce,
-                ERR_AGENT_OWNER_CANNOT_SELF_REVIEW
-            );
-        }
+        let owner_mapper = self.external_agent_owner(identity_addr, agent_nonce);
+        require!(!owner_mapper.is_empty(), ERR_AGENT_NOT_FOUND);
+        require!(
+            owner_mapper.get() != caller,
+            ERR_AGENT_OWNER_CANNOT_SELF_REVIEW
+        );
 
         // 2. Validate decimals
         require!(value_decimals <= 18, ERR_INVALID_VALUE_DECIMALS);
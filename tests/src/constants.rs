@@ -7,7 +7,11 @@ pub const AGENT_OWNER: TestAddress = TestAddress::new("agent_owner");
 pub const CLIENT: TestAddress = TestAddress::new("client");
 pub const WORKER: TestAddress = TestAddress::new("worker");
 pub const VALIDATOR: TestAddress = TestAddress::new("validator");
+pub const VALIDATOR_2: TestAddress = TestAddress::new("validator_2");
+pub const VALIDATOR_3: TestAddress = TestAddress::new("validator_3");
 pub const EMPLOYER: TestAddress = TestAddress::new("employer");
+pub const ARBITER: TestAddress = TestAddress::new("arbiter");
+pub const TREASURY: TestAddress = TestAddress::new("treasury");
 
 // ── SC Addresses ──
 pub const IDENTITY_SC_ADDRESS: TestSCAddress = TestSCAddress::new("identity-registry");
@@ -317,6 +317,7 @@ impl CsInteract {
                 ManagedAddress::<StaticApi>::from(validator),
                 ManagedBuffer::<StaticApi>::from(request_uri),
                 ManagedBuffer::<StaticApi>::from(request_hash),
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
             )
             .returns(ReturnsResultUnmanaged)
             .run()
@@ -344,6 +345,7 @@ impl CsInteract {
                 ManagedAddress::<StaticApi>::from(&validator),
                 ManagedBuffer::<StaticApi>::from(request_uri),
                 ManagedBuffer::<StaticApi>::from(request_hash),
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
             )
             .returns(ExpectError(err_code, err_msg))
             .run()
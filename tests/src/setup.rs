@@ -1,22 +1,31 @@
 use crate::constants::*;
-use common::structs::{AgentDetails, JobData, MetadataEntry, ServiceConfigInput};
+use common::structs::{
+    AgentDetails, AgentStatus, JobData, MetadataEntry, ServiceConfigInput, ValidationRequestData,
+};
 use identity_registry::storage::StorageModule;
 use multiversx_sc::proxy_imports::MultiValue2;
+use multiversx_sc::codec::TopEncode;
 use multiversx_sc::proxy_imports::OptionalValue;
 use multiversx_sc::types::{
-    BigUint, EgldOrEsdtTokenPayment, EsdtTokenIdentifier, ManagedAddress, ManagedArgBuffer,
-    ManagedBuffer, MultiValueEncoded, ReturnsNewManagedAddress, ReturnsResult, TestEsdtTransfer,
-    TokenId,
+    BigUint, DurationSeconds, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EsdtTokenIdentifier,
+    ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedVec, MultiValueEncoded, NonZeroBigUint,
+    Payment, ReturnsNewManagedAddress, ReturnsResult, TestEsdtTransfer, TimestampSeconds, TokenId,
 };
 use multiversx_sc_scenario::{
-    ScenarioTxRun, ScenarioTxWhitebox, ScenarioWorld, api::StaticApi, imports::ExpectMessage,
+    DebugApi, ScenarioTxRun, ScenarioTxWhitebox, ScenarioWorld,
+    api::StaticApi,
+    imports::{ExpectMessage, ReturnsLogs},
+    scenario_model::Log,
 };
 use proxies::{
     identity_registry_proxy::IdentityRegistryProxy,
     reputation_registry_proxy::ReputationRegistryProxy,
-    validation_registry_proxy::ValidationRegistryProxy,
+    validation_registry_proxy::{
+        HeldPayment, ValidationFee, ValidationOutcome, ValidationRegistryProxy, ValidatorInfo, ValidatorResponse,
+    },
 };
 use validation_registry::storage::ExternalStorageModule;
+use validation_registry::structs::LegacyValidationRequestData;
 
 pub fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -26,6 +35,64 @@ pub fn world() -> ScenarioWorld {
     blockchain
 }
 
+/// Ed25519 public key for a deterministic test seed.
+pub fn agent_public_key(seed: &[u8; 32]) -> Vec<u8> {
+    ed25519_dalek::SigningKey::from_bytes(seed)
+        .verifying_key()
+        .to_bytes()
+        .to_vec()
+}
+
+/// Sign the message checked by `submit_signed_proof` on the validation registry, for the
+/// job's current `proof_nonce`.
+pub fn sign_proof(seed: &[u8; 32], job_id: &[u8], proof_nonce: u64, proof: &[u8]) -> Vec<u8> {
+    sign_proof_for(
+        &VALIDATION_SC_ADDRESS.eval_to_array(),
+        seed,
+        job_id,
+        proof_nonce,
+        proof,
+    )
+}
+
+/// Sign `contract || u64 BE proof_nonce || u32 BE len(job_id) || job_id || proof`.
+pub fn sign_proof_for(
+    contract: &[u8; 32],
+    seed: &[u8; 32],
+    job_id: &[u8],
+    proof_nonce: u64,
+    proof: &[u8],
+) -> Vec<u8> {
+    use ed25519_dalek::Signer;
+
+    let mut message = contract.to_vec();
+    message.extend_from_slice(&proof_nonce.to_be_bytes());
+    message.extend_from_slice(&(job_id.len() as u32).to_be_bytes());
+    message.extend_from_slice(job_id);
+    message.extend_from_slice(proof);
+    ed25519_dalek::SigningKey::from_bytes(seed)
+        .sign(&message)
+        .to_bytes()
+        .to_vec()
+}
+
+/// keccak256 digest, as `validation_request` computes it over the request content.
+pub fn keccak256(data: &[u8]) -> Vec<u8> {
+    use sha3::Digest;
+
+    sha3::Keccak256::digest(data).to_vec()
+}
+
+/// The single log emitted under `identifier` (its first topic).
+pub fn find_log<'a>(logs: &'a [Log], identifier: &[u8]) -> &'a Log {
+    let mut matching = logs
+        .iter()
+        .filter(|log| log.topics.first().map(Vec::as_slice) == Some(identifier));
+    let log = matching.next().expect("event not emitted");
+    assert!(matching.next().is_none(), "event emitted more than once");
+    log
+}
+
 pub struct AgentTestState {
     pub world: ScenarioWorld,
     pub identity_sc: ManagedAddress<StaticApi>,
@@ -98,6 +165,9 @@ impl AgentTestState {
             .esdt_balance(WRONG_TOKEN, 1_000_000_000u64);
         world.account(WORKER).nonce(1).balance(1_000_000u64);
         world.account(VALIDATOR).nonce(1).balance(1_000_000u64);
+        world.account(VALIDATOR_2).nonce(1).balance(1_000_000u64);
+        world.account(VALIDATOR_3).nonce(1).balance(1_000_000u64);
+        world.account(TREASURY).nonce(1);
 
         Self {
             world,
@@ -321,313 +391,1625 @@ impl AgentTestState {
             .run();
     }
 
-    // ── Validation Registry ──
-
-    pub fn init_job(
+    pub fn set_service_pass_threshold(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        agent_nonce: u64,
-        service_id: Option<u32>,
+        nonce: u64,
+        service_id: u32,
+        threshold: u8,
     ) {
-        let svc = match service_id {
-            Some(sid) => OptionalValue::Some(sid),
-            None => OptionalValue::None,
-        };
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .init_job(ManagedBuffer::from(job_id), agent_nonce, svc)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_service_pass_threshold(nonce, service_id, threshold)
             .run();
     }
 
-    pub fn init_job_with_payment(
+    pub fn set_service_pass_threshold_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        agent_nonce: u64,
+        nonce: u64,
         service_id: u32,
-        token: &str,
-        token_nonce: u64,
-        amount: u64,
+        threshold: u8,
+        err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .init_job(
-                ManagedBuffer::from(job_id),
-                agent_nonce,
-                OptionalValue::Some(service_id),
-            )
-            .esdt(TestEsdtTransfer(
-                multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
-                token_nonce,
-                amount,
-            ))
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_service_pass_threshold(nonce, service_id, threshold)
+            .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn init_job_with_payment_expect_err(
+    pub fn query_service_pass_threshold(&mut self, nonce: u64, service_id: u32) -> OptionalValue<u8> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_service_pass_threshold(nonce, service_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn set_service_pay_on_verification(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        agent_nonce: u64,
+        nonce: u64,
         service_id: u32,
-        token: &str,
-        token_nonce: u64,
-        amount: u64,
-        err_msg: &str,
+        enabled: bool,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .init_job(
-                ManagedBuffer::from(job_id),
-                agent_nonce,
-                OptionalValue::Some(service_id),
-            )
-            .esdt(TestEsdtTransfer(
-                multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
-                token_nonce,
-                amount,
-            ))
-            .returns(ExpectMessage(err_msg))
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_service_pay_on_verification(nonce, service_id, enabled)
             .run();
     }
 
-    pub fn submit_proof(
+    pub fn set_service_pay_on_verification_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        proof: &[u8],
+        nonce: u64,
+        service_id: u32,
+        enabled: bool,
+        err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_service_pay_on_verification(nonce, service_id, enabled)
+            .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn submit_proof_with_nft(
+    pub fn query_service_pay_on_verification(&mut self, nonce: u64, service_id: u32) -> bool {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .is_service_pay_on_verification(nonce, service_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    // ── Operators ──
+
+    pub fn set_agent_operator(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        proof: &[u8],
-        token_id: &multiversx_sc::types::TestTokenIdentifier,
         nonce: u64,
+        operator: &multiversx_sc::types::TestAddress,
+        permissions: u8,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .submit_proof_with_nft(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
-            .single_esdt(
-                &EsdtTokenIdentifier::from(*token_id),
-                nonce,
-                &BigUint::from(1u64),
-            )
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_agent_operator(nonce, operator.to_managed_address(), permissions)
             .run();
     }
 
-    pub fn submit_proof_with_nft_expect_err(
+    pub fn set_agent_operator_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        proof: &[u8],
-        token_id: &multiversx_sc::types::TestTokenIdentifier,
         nonce: u64,
+        operator: &multiversx_sc::types::TestAddress,
+        permissions: u8,
         err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .submit_proof_with_nft(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
-            .single_esdt(
-                &EsdtTokenIdentifier::from(*token_id),
-                nonce,
-                &BigUint::from(1u64),
-            )
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_agent_operator(nonce, operator.to_managed_address(), permissions)
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn validation_request(
+    pub fn remove_agent_operator(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        validator: &multiversx_sc::types::TestAddress,
-        request_uri: &[u8],
-        request_hash: &[u8],
+        nonce: u64,
+        operator: &multiversx_sc::types::TestAddress,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .validation_request(
-                ManagedBuffer::from(job_id),
-                validator.to_managed_address(),
-                ManagedBuffer::from(request_uri),
-                ManagedBuffer::from(request_hash),
-            )
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .remove_agent_operator(nonce, operator.to_managed_address())
             .run();
     }
 
-    pub fn validation_request_expect_err(
+    pub fn remove_agent_operator_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        validator: &multiversx_sc::types::TestAddress,
-        request_uri: &[u8],
-        request_hash: &[u8],
+        nonce: u64,
+        operator: &multiversx_sc::types::TestAddress,
         err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .validation_request(
-                ManagedBuffer::from(job_id),
-                validator.to_managed_address(),
-                ManagedBuffer::from(request_uri),
-                ManagedBuffer::from(request_hash),
-            )
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .remove_agent_operator(nonce, operator.to_managed_address())
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn validation_response(
+    pub fn query_operator_permissions(
+        &mut self,
+        nonce: u64,
+        operator: &multiversx_sc::types::TestAddress,
+    ) -> u8 {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_operator_permissions(nonce, operator.to_managed_address())
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    // ── Ownership transfer ──
+
+    pub fn propose_agent_transfer(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        request_hash: &[u8],
-        response: u8,
-        response_uri: &[u8],
-        response_hash: &[u8],
-        tag: &[u8],
+        nonce: u64,
+        new_owner: &multiversx_sc::types::TestAddress,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .validation_response(
-                ManagedBuffer::from(request_hash),
-                response,
-                ManagedBuffer::from(response_uri),
-                ManagedBuffer::from(response_hash),
-                ManagedBuffer::from(tag),
-            )
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .propose_agent_transfer(nonce, new_owner.to_managed_address())
             .run();
     }
 
-    pub fn validation_response_expect_err(
+    pub fn propose_agent_transfer_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        request_hash: &[u8],
-        response: u8,
-        response_uri: &[u8],
-        response_hash: &[u8],
-        tag: &[u8],
+        nonce: u64,
+        new_owner: &multiversx_sc::types::TestAddress,
         err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .validation_response(
-                ManagedBuffer::from(request_hash),
-                response,
-                ManagedBuffer::from(response_uri),
-                ManagedBuffer::from(response_hash),
-                ManagedBuffer::from(tag),
-            )
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .propose_agent_transfer(nonce, new_owner.to_managed_address())
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn clean_old_jobs(&mut self, job_ids: Vec<&[u8]>) {
-        let mut ids_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
-        for id in &job_ids {
-            ids_encoded.push(ManagedBuffer::from(*id));
-        }
+    pub fn accept_agent_transfer(&mut self, from: &multiversx_sc::types::TestAddress, nonce: u64) {
         self.world
             .tx()
-            .from(OWNER_ADDRESS)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .clean_old_jobs(ids_encoded)
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .accept_agent_transfer(nonce)
             .run();
     }
 
-    // ── Reputation Registry ──
-
-
-    pub fn give_feedback_simple(
+    pub fn accept_agent_transfer_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        agent_nonce: u64,
-        rating: u64,
+        nonce: u64,
+        err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(REPUTATION_SC_ADDRESS)
-            .typed(ReputationRegistryProxy)
-            .give_feedback_simple(
-                ManagedBuffer::from(job_id),
-                agent_nonce,
-                BigUint::from(rating),
-            )
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .accept_agent_transfer(nonce)
+            .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn give_feedback_simple_expect_err(
-        &mut self,
-        from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        agent_nonce: u64,
-        rating: u64,
-        err_msg: &str,
-    ) {
+    pub fn cancel_agent_transfer(&mut self, from: &multiversx_sc::types::TestAddress, nonce: u64) {
         self.world
             .tx()
             .from(*from)
-            .to(REPUTATION_SC_ADDRESS)
-            .typed(ReputationRegistryProxy)
-            .give_feedback_simple(
-                ManagedBuffer::from(job_id),
-                agent_nonce,
-                BigUint::from(rating),
-            )
-            .returns(ExpectMessage(err_msg))
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .cancel_agent_transfer(nonce)
             .run();
     }
 
-    pub fn append_response(
-        &mut self,
-        from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        response_uri: &[u8],
-    ) {
+    // ── Multi-agent ownership ──
+
+    pub fn set_multi_agent_mode(&mut self, enabled: bool) {
         self.world
             .tx()
-            .from(*from)
-            .to(REPUTATION_SC_ADDRESS)
-            .typed(ReputationRegistryProxy)
+            .from(OWNER_ADDRESS)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_multi_agent_mode(enabled)
+            .run();
+    }
+
+    /// Write an owner entry in the pre-multi-agent `agents` BiDiMapper layout.
+    pub fn seed_legacy_agent(&mut self, nonce: u64, owner: &multiversx_sc::types::TestAddress) {
+        let owner = owner.to_address();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(IDENTITY_SC_ADDRESS)
+            .whitebox(identity_registry::contract_obj, |sc| {
+                sc.legacy_agents()
+                    .insert(nonce, ManagedAddress::from_address(&owner));
+            });
+    }
+
+    /// Send an agent NFT from the registry to its recorded owner, as registrations made
+    /// before custody left it in the owner's wallet.
+    pub fn move_agent_nft_to_owner(&mut self, nonce: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(IDENTITY_SC_ADDRESS)
+            .whitebox(identity_registry::contract_obj, |sc| {
+                let token_id = sc.agent_token_id().get_token_id();
+                let owner = sc.agent_owner(nonce).get();
+                multiversx_sc::contract_base::ContractBase::tx(&sc)
+                    .to(&owner)
+                    .single_esdt(&token_id, nonce, &BigUint::from(1u64))
+                    .transfer();
+            });
+    }
+
+    // ── Status ──
+
+    pub fn pause_agent(&mut self, from: &multiversx_sc::types::TestAddress, nonce: u64) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .pause_agent(nonce)
+            .run();
+    }
+
+    pub fn pause_agent_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .pause_agent(nonce)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn resume_agent(&mut self, from: &multiversx_sc::types::TestAddress, nonce: u64) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .resume_agent(nonce)
+            .run();
+    }
+
+    pub fn resume_agent_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .resume_agent(nonce)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_agent_status(&mut self, nonce: u64) -> AgentStatus {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .agent_status(nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    // ── Deregistration ──
+
+    pub fn deregister_agent(&mut self, from: &multiversx_sc::types::TestAddress, nonce: u64) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .deregister_agent(nonce)
+            .run();
+    }
+
+    pub fn deregister_agent_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .deregister_agent(nonce)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    /// Assert the identity NFT is burned and no per-nonce storage is left behind.
+    pub fn check_agent_retired(&mut self, nonce: u64) {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .whitebox(identity_registry::contract_obj, |sc| {
+                let token_id = sc.agent_token_id().get_token_id();
+                let balance = multiversx_sc::contract_base::ContractBase::blockchain(&sc)
+                    .get_sc_balance(EgldOrEsdtTokenIdentifier::esdt(token_id), nonce);
+                assert_eq!(balance, 0u64);
+                assert!(sc.agent_owner(nonce).is_empty());
+                assert!(sc.agent_details(nonce).is_empty());
+                assert!(sc.agent_metadata(nonce).is_empty());
+                assert!(sc.agent_service_config(nonce).is_empty());
+                assert!(sc.agent_status(nonce).is_empty());
+                assert!(sc.agent_operators(nonce).is_empty());
+                assert!(sc.pending_agent_transfer(nonce).is_empty());
+            });
+    }
+
+    // ── Validation Registry ──
+
+    pub fn init_job(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: Option<u32>,
+    ) {
+        let svc = match service_id {
+            Some(sid) => OptionalValue::Some(sid),
+            None => OptionalValue::None,
+        };
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(ManagedBuffer::from(job_id), agent_nonce, svc)
+            .run();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn init_job_with_payment(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        token: &str,
+        token_nonce: u64,
+        amount: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::Some(service_id),
+            )
+            .esdt(TestEsdtTransfer(
+                multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
+                token_nonce,
+                amount,
+            ))
+            .run();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn init_job_with_payment_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        token: &str,
+        token_nonce: u64,
+        amount: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::Some(service_id),
+            )
+            .esdt(TestEsdtTransfer(
+                multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
+                token_nonce,
+                amount,
+            ))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    fn payment_vec(payments: &[(&str, u64)]) -> ManagedVec<StaticApi, Payment<StaticApi>> {
+        let mut vec = ManagedVec::new();
+        for (token, amount) in payments {
+            vec.push(Payment::new(
+                TokenId::from(*token),
+                0,
+                NonZeroBigUint::new_or_panic(BigUint::from(*amount)),
+            ));
+        }
+        vec
+    }
+
+    pub fn init_job_with_payments(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        payments: &[(&str, u64)],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::Some(service_id),
+            )
+            .payment(Self::payment_vec(payments))
+            .run();
+    }
+
+    pub fn init_job_with_payments_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        payments: &[(&str, u64)],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::Some(service_id),
+            )
+            .payment(Self::payment_vec(payments))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn cancel_job(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .cancel_job(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn cancel_job_with_logs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+    ) -> Vec<Log> {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .cancel_job(ManagedBuffer::from(job_id))
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    pub fn cancel_job_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .cancel_job(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn enable_job_milestones(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .enable_job_milestones(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn enable_job_milestones_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .enable_job_milestones(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_is_milestone_job(&mut self, job_id: &[u8]) -> bool {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .job_milestones(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn submit_proof(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .run();
+    }
+
+    pub fn submit_proof_with_logs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+    ) -> Vec<Log> {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    pub fn submit_signed_proof(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+        signature: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_signed_proof(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(proof),
+                ManagedBuffer::from(signature),
+            )
+            .run();
+    }
+
+    pub fn submit_signed_proof_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+        signature: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_signed_proof(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(proof),
+                ManagedBuffer::from(signature),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn validation_request(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_uri: &[u8],
+        request_hash: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(request_uri),
+                ManagedBuffer::from(request_hash),
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .run();
+    }
+
+    pub fn validation_request_with_content(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        request_content: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(b"https://val.uri"),
+                ManagedBuffer::from(request_hash),
+                OptionalValue::Some(ManagedBuffer::from(request_content)),
+            )
+            .run();
+    }
+
+    pub fn validation_request_with_content_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        request_content: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(b"https://val.uri"),
+                ManagedBuffer::from(request_hash),
+                OptionalValue::Some(ManagedBuffer::from(request_content)),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_job_validation_history(&mut self, job_id: &[u8]) -> Vec<ManagedBuffer<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_validation_history(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn validation_request_with_logs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_uri: &[u8],
+        request_hash: &[u8],
+    ) -> Vec<Log> {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(request_uri),
+                ManagedBuffer::from(request_hash),
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    pub fn validation_request_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_uri: &[u8],
+        request_hash: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(request_uri),
+                ManagedBuffer::from(request_hash),
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn quorum_validation_request(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validators: Vec<&multiversx_sc::types::TestAddress>,
+        quorum: u32,
+        request_hash: &[u8],
+    ) {
+        let mut validators_encoded = MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+        for validator in &validators {
+            validators_encoded.push(validator.to_managed_address());
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .quorum_validation_request(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(b"https://val.uri"),
+                ManagedBuffer::from(request_hash),
+                quorum,
+                validators_encoded,
+            )
+            .run();
+    }
+
+    pub fn quorum_validation_request_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validators: Vec<&multiversx_sc::types::TestAddress>,
+        quorum: u32,
+        request_hash: &[u8],
+        err_msg: &str,
+    ) {
+        let mut validators_encoded = MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+        for validator in &validators {
+            validators_encoded.push(validator.to_managed_address());
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .quorum_validation_request(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(b"https://val.uri"),
+                ManagedBuffer::from(request_hash),
+                quorum,
+                validators_encoded,
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn validation_request_with_fee(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        fee: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(b"https://val.uri"),
+                ManagedBuffer::from(request_hash),
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .egld(fee)
+            .run();
+    }
+
+    pub fn quorum_validation_request_with_fee(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validators: Vec<&multiversx_sc::types::TestAddress>,
+        quorum: u32,
+        request_hash: &[u8],
+        fee: u64,
+    ) {
+        let mut validators_encoded = MultiValueEncoded::<StaticApi, ManagedAddress<StaticApi>>::new();
+        for validator in &validators {
+            validators_encoded.push(validator.to_managed_address());
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .quorum_validation_request(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(b"https://val.uri"),
+                ManagedBuffer::from(request_hash),
+                quorum,
+                validators_encoded,
+            )
+            .egld(fee)
+            .run();
+    }
+
+    pub fn refund_validation_fee(&mut self, from: &multiversx_sc::types::TestAddress, request_hash: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .refund_validation_fee(ManagedBuffer::from(request_hash))
+            .run();
+    }
+
+    pub fn refund_validation_fee_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .refund_validation_fee(ManagedBuffer::from(request_hash))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_payment_hold_timeout(&mut self, timeout: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_payment_hold_timeout(DurationSeconds::new(timeout))
+            .run();
+    }
+
+    pub fn set_protocol_fee(&mut self, fee_bps: u32) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_protocol_fee(fee_bps)
+            .run();
+    }
+
+    pub fn set_treasury(&mut self, treasury: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_treasury(treasury.to_managed_address())
+            .run();
+    }
+
+    pub fn claim_fees(&mut self, from: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .claim_fees()
+            .run();
+    }
+
+    pub fn claim_fees_with_logs(&mut self, from: &multiversx_sc::types::TestAddress) -> Vec<Log> {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .claim_fees()
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    pub fn claim_fees_expect_err(&mut self, from: &multiversx_sc::types::TestAddress, err_msg: &str) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .claim_fees()
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_accumulated_fees(&mut self) -> Vec<Payment<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_accumulated_fees()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn refund_held_payment(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .refund_held_payment(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn refund_held_payment_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .refund_held_payment(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_held_payment(&mut self, job_id: &[u8]) -> OptionalValue<HeldPayment<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_held_payment(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn set_validator_fee_timeout(&mut self, timeout: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_validator_fee_timeout(DurationSeconds::new(timeout))
+            .run();
+    }
+
+    pub fn query_validation_fee(&mut self, request_hash: &[u8]) -> OptionalValue<ValidationFee<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validation_fee(ManagedBuffer::from(request_hash))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn expire_validation_request(&mut self, from: &multiversx_sc::types::TestAddress, request_hash: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .expire_validation_request(ManagedBuffer::from(request_hash))
+            .run();
+    }
+
+    pub fn expire_validation_request_with_logs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+    ) -> Vec<Log> {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .expire_validation_request(ManagedBuffer::from(request_hash))
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    pub fn expire_validation_request_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .expire_validation_request(ManagedBuffer::from(request_hash))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn renominate_validator(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        previous_validator: &multiversx_sc::types::TestAddress,
+        new_validator: &multiversx_sc::types::TestAddress,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .renominate_validator(
+                ManagedBuffer::from(request_hash),
+                previous_validator.to_managed_address(),
+                new_validator.to_managed_address(),
+            )
+            .run();
+    }
+
+    pub fn renominate_validator_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        previous_validator: &multiversx_sc::types::TestAddress,
+        new_validator: &multiversx_sc::types::TestAddress,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .renominate_validator(
+                ManagedBuffer::from(request_hash),
+                previous_validator.to_managed_address(),
+                new_validator.to_managed_address(),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_validation_request_timeout(&mut self, timeout: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_validation_request_timeout(DurationSeconds::new(timeout))
+            .run();
+    }
+
+    pub fn set_expiry_grace_period(&mut self, grace_period: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_expiry_grace_period(DurationSeconds::new(grace_period))
+            .run();
+    }
+
+    pub fn query_validator_response(
+        &mut self,
+        request_hash: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+    ) -> OptionalValue<ValidatorResponse<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validator_response(ManagedBuffer::from(request_hash), validator.to_managed_address())
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_validation_status(
+        &mut self,
+        request_hash: &[u8],
+    ) -> Option<ValidationRequestData<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validation_status(ManagedBuffer::from(request_hash))
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    pub fn query_validation_outcome(&mut self, request_hash: &[u8]) -> ValidationOutcome {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validation_outcome(ManagedBuffer::from(request_hash))
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+            .unwrap()
+    }
+
+    pub fn validation_response(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        response: u8,
+        response_uri: &[u8],
+        response_hash: &[u8],
+        tag: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_response(
+                ManagedBuffer::from(request_hash),
+                response,
+                ManagedBuffer::from(response_uri),
+                ManagedBuffer::from(response_hash),
+                ManagedBuffer::from(tag),
+            )
+            .run();
+    }
+
+    pub fn validation_response_with_logs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        response: u8,
+        response_uri: &[u8],
+        response_hash: &[u8],
+        tag: &[u8],
+    ) -> Vec<Log> {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_response(
+                ManagedBuffer::from(request_hash),
+                response,
+                ManagedBuffer::from(response_uri),
+                ManagedBuffer::from(response_hash),
+                ManagedBuffer::from(tag),
+            )
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn validation_response_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        response: u8,
+        response_uri: &[u8],
+        response_hash: &[u8],
+        tag: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_response(
+                ManagedBuffer::from(request_hash),
+                response,
+                ManagedBuffer::from(response_uri),
+                ManagedBuffer::from(response_hash),
+                ManagedBuffer::from(tag),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn clean_old_jobs(&mut self, job_ids: Vec<&[u8]>) {
+        let mut ids_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in &job_ids {
+            ids_encoded.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .clean_old_jobs(ids_encoded)
+            .run();
+    }
+
+    // ── Validator registry ──
+
+    pub fn set_validator_staking(&mut self, min_stake: u64, unbonding_period: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_validator_staking(
+                EgldOrEsdtTokenIdentifier::egld(),
+                BigUint::from(min_stake),
+                DurationSeconds::new(unbonding_period),
+            )
+            .run();
+    }
+
+    pub fn set_require_staked_validators(&mut self, required: bool) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_require_staked_validators(required)
+            .run();
+    }
+
+    pub fn register_validator(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        stake: u64,
+        tags: Vec<&[u8]>,
+    ) {
+        let mut tags_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for tag in &tags {
+            tags_encoded.push(ManagedBuffer::from(*tag));
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .register_validator(tags_encoded)
+            .egld(stake)
+            .run();
+    }
+
+    pub fn register_validator_with_logs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        stake: u64,
+        tags: Vec<&[u8]>,
+    ) -> Vec<Log> {
+        let mut tags_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for tag in &tags {
+            tags_encoded.push(ManagedBuffer::from(*tag));
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .register_validator(tags_encoded)
+            .egld(stake)
+            .returns(ReturnsLogs)
+            .run()
+    }
+
+    pub fn register_validator_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        stake: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .register_validator(MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new())
+            .egld(stake)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_validator_tags(&mut self, from: &multiversx_sc::types::TestAddress, tags: Vec<&[u8]>) {
+        let mut tags_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for tag in &tags {
+            tags_encoded.push(ManagedBuffer::from(*tag));
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_validator_tags(tags_encoded)
+            .run();
+    }
+
+    pub fn unbond_validator(&mut self, from: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .unbond_validator()
+            .run();
+    }
+
+    pub fn withdraw_validator_stake(&mut self, from: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .withdraw_validator_stake()
+            .run();
+    }
+
+    pub fn withdraw_validator_stake_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .withdraw_validator_stake()
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn slash_validator(
+        &mut self,
+        validator: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        amount: u64,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .slash_validator(
+                validator.to_managed_address(),
+                ManagedBuffer::from(request_hash),
+                BigUint::from(amount),
+            )
+            .run();
+    }
+
+    pub fn slash_validator_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        validator: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        amount: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .slash_validator(
+                validator.to_managed_address(),
+                ManagedBuffer::from(request_hash),
+                BigUint::from(amount),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_validator(
+        &mut self,
+        validator: &multiversx_sc::types::TestAddress,
+    ) -> OptionalValue<ValidatorInfo<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validator(validator.to_managed_address())
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_validators_by_tag(&mut self, tag: &[u8]) -> Vec<ManagedAddress<StaticApi>> {
+        let result = self
+            .world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validators_by_tag(ManagedBuffer::from(tag))
+            .returns(ReturnsResult)
+            .run();
+        result.into_iter().collect()
+    }
+
+    // ── Reputation Registry ──
+
+
+    pub fn give_feedback_simple(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        rating: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback_simple(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                BigUint::from(rating),
+            )
+            .run();
+    }
+
+    pub fn give_feedback_simple_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        rating: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback_simple(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                BigUint::from(rating),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn give_feedback(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        value: i64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback(
+                agent_nonce,
+                value,
+                0u8,
+                ManagedBuffer::from(b"quality"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+            )
+            .run();
+    }
+
+    pub fn give_feedback_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        value: i64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback(
+                agent_nonce,
+                value,
+                0u8,
+                ManagedBuffer::from(b"quality"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn append_response(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        response_uri: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
             .append_response(
                 ManagedBuffer::from(job_id),
                 ManagedBuffer::from(response_uri),
@@ -657,6 +2039,19 @@ impl AgentTestState {
             .run()
     }
 
+    pub fn query_pending_agent_transfer(
+        &mut self,
+        nonce: u64,
+    ) -> OptionalValue<ManagedAddress<StaticApi>> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_pending_agent_transfer(nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
     pub fn query_metadata(
         &mut self,
         nonce: u64,
@@ -685,6 +2080,22 @@ impl AgentTestState {
             .run()
     }
 
+    pub fn query_service_payments(
+        &mut self,
+        nonce: u64,
+        service_id: u32,
+    ) -> Vec<EgldOrEsdtTokenPayment<StaticApi>> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_agent_service_payments(nonce, service_id)
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
     pub fn query_is_job_verified(&mut self, job_id: &[u8]) -> bool {
         self.world
             .query()
@@ -695,6 +2106,16 @@ impl AgentTestState {
             .run()
     }
 
+    pub fn query_is_request_passed(&mut self, request_hash: &[u8]) -> bool {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .request_passed(ManagedBuffer::from(request_hash))
+            .returns(ReturnsResult)
+            .run()
+    }
+
     pub fn query_job_data(&mut self, job_id: &[u8]) -> OptionalValue<JobData<StaticApi>> {
         self.world
             .query()
@@ -705,6 +2126,16 @@ impl AgentTestState {
             .run()
     }
 
+    pub fn query_proof_nonce(&mut self, job_id: &[u8]) -> u64 {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .job_proof_nonce(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
     pub fn query_reputation_score(&mut self, agent_nonce: u64) -> BigUint<StaticApi> {
         self.world
             .query()
@@ -750,27 +2181,112 @@ impl AgentTestState {
     // ── Upgrade helpers ──
 
     pub fn upgrade_identity(&mut self) {
+        self.upgrade_identity_migrating(&[]);
+    }
+
+    /// Upgrade the identity registry, migrating `agent_nonces` out of the legacy owner map.
+    pub fn upgrade_identity_migrating(&mut self, agent_nonces: &[u64]) {
+        let mut nonces = MultiValueEncoded::<StaticApi, u64>::new();
+        for nonce in agent_nonces {
+            nonces.push(*nonce);
+        }
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(IDENTITY_SC_ADDRESS)
             .typed(IdentityRegistryProxy)
-            .upgrade()
+            .upgrade(nonces)
             .code(IDENTITY_CODE)
             .run();
     }
 
+    pub fn migrate_legacy_agents(&mut self, from: &multiversx_sc::types::TestAddress, agent_nonces: &[u64]) {
+        let mut nonces = MultiValueEncoded::<StaticApi, u64>::new();
+        for nonce in agent_nonces {
+            nonces.push(*nonce);
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .migrate_legacy_agents(nonces)
+            .run();
+    }
+
+    pub fn migrate_legacy_agents_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonces: &[u64],
+        err_msg: &str,
+    ) {
+        let mut nonces = MultiValueEncoded::<StaticApi, u64>::new();
+        for nonce in agent_nonces {
+            nonces.push(*nonce);
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .migrate_legacy_agents(nonces)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
     pub fn upgrade_validation(&mut self) {
+        self.upgrade_validation_migrating(&[]);
+    }
+
+    /// Upgrade the validation registry, migrating the validation records of `agent_nonces`.
+    pub fn upgrade_validation_migrating(&mut self, agent_nonces: &[u64]) {
+        let mut nonces = MultiValueEncoded::<StaticApi, u64>::new();
+        for nonce in agent_nonces {
+            nonces.push(*nonce);
+        }
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .upgrade()
+            .upgrade(nonces)
             .code(VALIDATION_CODE)
             .run();
     }
 
+    /// Whitebox helper: store a validation request in the layout used before URIs were stored.
+    pub fn store_legacy_validation_request(
+        &mut self,
+        agent_nonce: u64,
+        job_id: &[u8],
+        request_hash: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        response: u8,
+    ) {
+        let validator = validator.eval_to_array();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .whitebox(validation_registry::contract_obj, |sc| {
+                let legacy = LegacyValidationRequestData::<DebugApi> {
+                    validator_address: ManagedAddress::from(validator),
+                    agent_nonce,
+                    job_id: ManagedBuffer::from(job_id),
+                    response,
+                    response_hash: ManagedBuffer::from(b"legacy_response_hash"),
+                    tag: ManagedBuffer::new(),
+                    last_update: TimestampSeconds::new(1),
+                };
+                let mut raw = ManagedBuffer::new();
+                legacy.top_encode(&mut raw).unwrap();
+
+                let request_hash = ManagedBuffer::from(request_hash);
+                sc.raw_validation_request_data(&request_hash).set(raw);
+                sc.agent_validations(agent_nonce).insert(request_hash);
+            });
+    }
+
     pub fn upgrade_reputation(&mut self) {
         self.world
             .tx()
@@ -801,10 +2317,11 @@ impl AgentTestState {
 
     // ── Update agent (raw call with NFT transfer) ──
 
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     pub fn update_agent_raw(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        nft_nonce: u64,
+        nonce: u64,
         new_name: &[u8],
         new_uri: &[u8],
         new_public_key: &[u8],
@@ -812,6 +2329,7 @@ impl AgentTestState {
         services: Option<Vec<(u32, u64, &[u8], u64)>>,
     ) {
         let mut args = ManagedArgBuffer::<StaticApi>::new();
+        args.push_arg(nonce);
         args.push_arg(ManagedBuffer::<StaticApi>::from(new_name));
         args.push_arg(ManagedBuffer::<StaticApi>::from(new_uri));
         args.push_arg(ManagedBuffer::<StaticApi>::from(new_public_key));
@@ -846,20 +2364,20 @@ impl AgentTestState {
             .to(IDENTITY_SC_ADDRESS)
             .raw_call("update_agent")
             .arguments_raw(args)
-            .esdt(TestEsdtTransfer(AGENT_TOKEN, nft_nonce, 1))
             .run();
     }
 
     pub fn update_agent_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        nft_nonce: u64,
+        nonce: u64,
         new_name: &[u8],
         new_uri: &[u8],
         new_public_key: &[u8],
         err_msg: &str,
     ) {
         let mut args = ManagedArgBuffer::<StaticApi>::new();
+        args.push_arg(nonce);
         args.push_arg(ManagedBuffer::<StaticApi>::from(new_name));
         args.push_arg(ManagedBuffer::<StaticApi>::from(new_uri));
         args.push_arg(ManagedBuffer::<StaticApi>::from(new_public_key));
@@ -868,9 +2386,74 @@ impl AgentTestState {
             .tx()
             .from(*from)
             .to(IDENTITY_SC_ADDRESS)
-            .raw_call("update_agent")
-            .arguments_raw(args)
-            .esdt(TestEsdtTransfer(AGENT_TOKEN, nft_nonce, 1))
+            .raw_call("update_agent")
+            .arguments_raw(args)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    // ── Soulbound checks ──
+
+    /// Plain wallet-to-wallet ESDT transfer of an agent NFT.
+    pub fn transfer_agent_nft(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        to: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(*to)
+            .raw_call("")
+            .esdt(TestEsdtTransfer(AGENT_TOKEN, nonce, 1))
+            .run();
+    }
+
+    /// Plain wallet-to-wallet ESDT transfer of an agent NFT, expected to fail.
+    pub fn transfer_agent_nft_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        to: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(*to)
+            .raw_call("")
+            .esdt(TestEsdtTransfer(AGENT_TOKEN, nonce, 1))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn custody_agent_nft(&mut self, from: &multiversx_sc::types::TestAddress, nonce: u64) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .custody_agent_nft()
+            .esdt(TestEsdtTransfer(AGENT_TOKEN, nonce, 1))
+            .run();
+    }
+
+    pub fn custody_agent_nft_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        token: multiversx_sc_scenario::imports::TestTokenIdentifier,
+        nonce: u64,
+        amount: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .custody_agent_nft()
+            .esdt(TestEsdtTransfer(token, nonce, amount))
             .returns(ExpectMessage(err_msg))
             .run();
     }
@@ -907,6 +2490,42 @@ impl AgentTestState {
             .run();
     }
 
+    pub fn set_pass_threshold(&mut self, from: &multiversx_sc::types::TestAddress, threshold: u8) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_pass_threshold(threshold)
+            .run();
+    }
+
+    pub fn set_pass_threshold_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        threshold: u8,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_pass_threshold(threshold)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_pass_threshold(&mut self) -> u8 {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .pass_threshold()
+            .returns(ReturnsResult)
+            .run()
+    }
+
     pub fn set_reputation_identity_address(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1125,6 +2744,7 @@ impl AgentTestState {
             .run();
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn init_job_with_wrong_token_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1167,14 +2787,29 @@ impl AgentTestState {
             .run()
     }
 
-    pub fn query_agents(
+    pub fn query_agents_by_owner(
         &mut self,
-    ) -> MultiValueEncoded<StaticApi, MultiValue2<u64, ManagedAddress<StaticApi>>> {
+        owner: &multiversx_sc::types::TestAddress,
+        from: usize,
+        size: usize,
+    ) -> Vec<u64> {
+        let result = self
+            .world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_agents_by_owner(owner.to_managed_address(), from, size)
+            .returns(ReturnsResult)
+            .run();
+        result.into_iter().collect()
+    }
+
+    pub fn query_agent_count_by_owner(&mut self, owner: &multiversx_sc::types::TestAddress) -> usize {
         self.world
             .query()
             .to(IDENTITY_SC_ADDRESS)
             .typed(IdentityRegistryProxy)
-            .agents()
+            .get_agent_count_by_owner(owner.to_managed_address())
             .returns(ReturnsResult)
             .run()
     }
@@ -1206,7 +2841,7 @@ impl AgentTestState {
     pub fn query_agent_service_bulk(
         &mut self,
         nonce: u64,
-    ) -> MultiValueEncoded<StaticApi, MultiValue2<u32, multiversx_sc::types::Payment<StaticApi>>>
+    ) -> MultiValueEncoded<StaticApi, MultiValue2<u32, ManagedVec<StaticApi, Payment<StaticApi>>>>
     {
         self.world
             .query()
@@ -1242,7 +2877,9 @@ impl AgentTestState {
 // Escrow Test State — extends AgentTestState with Escrow SC
 // ════════════════════════════════════════════════════════════
 
-use escrow::storage::EscrowData;
+use escrow::storage::{
+    DisputeData, EscrowData, EscrowStatus, LegacyEscrowData, Milestone, StorageModule as _,
+};
 use proxies::escrow_proxy::EscrowProxy;
 
 pub struct EscrowTestState {
@@ -1336,6 +2973,8 @@ impl EscrowTestState {
             .esdt_balance(WRONG_TOKEN, 1_000_000_000u64);
         world.account(WORKER).nonce(1).balance(1_000_000u64);
         world.account(VALIDATOR).nonce(1).balance(1_000_000u64);
+        world.account(ARBITER).nonce(1).balance(1_000_000u64);
+        world.account(TREASURY).nonce(1);
         world
             .account(EMPLOYER)
             .nonce(1)
@@ -1391,8 +3030,32 @@ impl EscrowTestState {
             .run();
     }
 
+    pub fn pause_agent(&mut self, from: &multiversx_sc::types::TestAddress, nonce: u64) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .pause_agent(nonce)
+            .run();
+    }
+
     // ── Validation helpers ──
 
+    pub fn clean_old_jobs(&mut self, job_ids: Vec<&[u8]>) {
+        let mut ids_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in &job_ids {
+            ids_encoded.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .clean_old_jobs(ids_encoded)
+            .run();
+    }
+
     pub fn init_job(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1413,101 +3076,295 @@ impl EscrowTestState {
             .run();
     }
 
+    pub fn cancel_job(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .cancel_job(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn enable_job_milestones(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .enable_job_milestones(ManagedBuffer::from(job_id))
+            .run();
+    }
+
     pub fn submit_proof(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
-        proof: &[u8],
+        proof: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .run();
+    }
+
+    pub fn validation_request(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        validator: &multiversx_sc::types::TestAddress,
+        request_uri: &[u8],
+        request_hash: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                validator.to_managed_address(),
+                ManagedBuffer::from(request_uri),
+                ManagedBuffer::from(request_hash),
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .run();
+    }
+
+    pub fn validation_response(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        request_hash: &[u8],
+        response: u8,
+        response_uri: &[u8],
+        response_hash: &[u8],
+        tag: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_response(
+                ManagedBuffer::from(request_hash),
+                response,
+                ManagedBuffer::from(response_uri),
+                ManagedBuffer::from(response_hash),
+                ManagedBuffer::from(tag),
+            )
+            .run();
+    }
+
+    // ── Escrow actions ──
+
+    pub fn deposit_egld(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver: &multiversx_sc::types::TestAddress,
+        poa_hash: &[u8],
+        deadline: u64,
+        amount: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit(
+                ManagedBuffer::from(job_id),
+                receiver.to_managed_address(),
+                ManagedBuffer::from(poa_hash),
+                deadline,
+            )
+            .egld(amount)
+            .run();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit_egld_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver: &multiversx_sc::types::TestAddress,
+        poa_hash: &[u8],
+        deadline: u64,
+        amount: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit(
+                ManagedBuffer::from(job_id),
+                receiver.to_managed_address(),
+                ManagedBuffer::from(poa_hash),
+                deadline,
+            )
+            .egld(amount)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    /// Deposits with the zero address as receiver, so the escrow pays the job's agent owner.
+    pub fn deposit_egld_to_agent(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        poa_hash: &[u8],
+        deadline: u64,
+        amount: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit(
+                ManagedBuffer::from(job_id),
+                ManagedAddress::<StaticApi>::zero(),
+                ManagedBuffer::from(poa_hash),
+                deadline,
+            )
+            .egld(amount)
+            .run();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit_esdt(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver: &multiversx_sc::types::TestAddress,
+        poa_hash: &[u8],
+        deadline: u64,
+        token: &str,
+        token_nonce: u64,
+        amount: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit(
+                ManagedBuffer::from(job_id),
+                receiver.to_managed_address(),
+                ManagedBuffer::from(poa_hash),
+                deadline,
+            )
+            .esdt(TestEsdtTransfer(
+                multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
+                token_nonce,
+                amount,
+            ))
+            .run();
+    }
+
+    pub fn release(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .release(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn release_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .release(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn validation_request(
-        &mut self,
-        from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        validator: &multiversx_sc::types::TestAddress,
-        request_uri: &[u8],
-        request_hash: &[u8],
-    ) {
+    pub fn refund(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .validation_request(
-                ManagedBuffer::from(job_id),
-                validator.to_managed_address(),
-                ManagedBuffer::from(request_uri),
-                ManagedBuffer::from(request_hash),
-            )
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .refund(ManagedBuffer::from(job_id))
             .run();
     }
 
-    pub fn validation_response(
+    pub fn refund_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        request_hash: &[u8],
-        response: u8,
-        response_uri: &[u8],
-        response_hash: &[u8],
-        tag: &[u8],
+        job_id: &[u8],
+        err_msg: &str,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .validation_response(
-                ManagedBuffer::from(request_hash),
-                response,
-                ManagedBuffer::from(response_uri),
-                ManagedBuffer::from(response_hash),
-                ManagedBuffer::from(tag),
-            )
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .refund(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    // ── Escrow actions ──
+    fn milestone_args(
+        milestones: &[(u64, &[u8])],
+    ) -> MultiValueEncoded<StaticApi, MultiValue2<BigUint<StaticApi>, ManagedBuffer<StaticApi>>> {
+        let mut args = MultiValueEncoded::new();
+        for (amount, deliverable_hash) in milestones {
+            args.push(MultiValue2::from((
+                BigUint::from(*amount),
+                ManagedBuffer::from(*deliverable_hash),
+            )));
+        }
+        args
+    }
 
-    pub fn deposit_egld(
+    pub fn deposit_milestones_egld(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
         receiver: &multiversx_sc::types::TestAddress,
-        poa_hash: &[u8],
         deadline: u64,
         amount: u64,
+        milestones: &[(u64, &[u8])],
     ) {
         self.world
             .tx()
             .from(*from)
             .to(ESCROW_SC_ADDRESS)
             .typed(EscrowProxy)
-            .deposit(
+            .deposit_milestones(
                 ManagedBuffer::from(job_id),
                 receiver.to_managed_address(),
-                ManagedBuffer::from(poa_hash),
+                ManagedBuffer::from(b"poa_hash"),
                 deadline,
+                Self::milestone_args(milestones),
             )
             .egld(amount)
             .run();
     }
 
-    pub fn deposit_egld_expect_err(
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit_milestones_egld_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
         receiver: &multiversx_sc::types::TestAddress,
-        poa_hash: &[u8],
         deadline: u64,
         amount: u64,
+        milestones: &[(u64, &[u8])],
         err_msg: &str,
     ) {
         self.world
@@ -1515,58 +3372,156 @@ impl EscrowTestState {
             .from(*from)
             .to(ESCROW_SC_ADDRESS)
             .typed(EscrowProxy)
-            .deposit(
+            .deposit_milestones(
                 ManagedBuffer::from(job_id),
                 receiver.to_managed_address(),
-                ManagedBuffer::from(poa_hash),
+                ManagedBuffer::from(b"poa_hash"),
                 deadline,
+                Self::milestone_args(milestones),
             )
             .egld(amount)
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn deposit_esdt(
+    pub fn release_milestone(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
-        receiver: &multiversx_sc::types::TestAddress,
-        poa_hash: &[u8],
-        deadline: u64,
-        token: &str,
-        token_nonce: u64,
-        amount: u64,
+        milestone_index: u32,
     ) {
         self.world
             .tx()
             .from(*from)
             .to(ESCROW_SC_ADDRESS)
             .typed(EscrowProxy)
-            .deposit(
-                ManagedBuffer::from(job_id),
-                receiver.to_managed_address(),
-                ManagedBuffer::from(poa_hash),
-                deadline,
-            )
-            .esdt(TestEsdtTransfer(
-                multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
-                token_nonce,
-                amount,
-            ))
+            .release_milestone(ManagedBuffer::from(job_id), milestone_index)
             .run();
     }
 
-    pub fn release(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+    pub fn release_milestone_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        milestone_index: u32,
+        err_msg: &str,
+    ) {
         self.world
             .tx()
             .from(*from)
             .to(ESCROW_SC_ADDRESS)
             .typed(EscrowProxy)
-            .release(ManagedBuffer::from(job_id))
+            .release_milestone(ManagedBuffer::from(job_id), milestone_index)
+            .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn release_expect_err(
+    pub fn add_arbiter(&mut self, arbiter: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .add_arbiter(arbiter.to_managed_address())
+            .run();
+    }
+
+    /// Let validation-registry see this escrow contract when cleaning old jobs.
+    pub fn link_validation_to_escrow(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_escrow_contract_address(ESCROW_SC_ADDRESS.to_managed_address())
+            .run();
+    }
+
+    pub fn set_dispute_timeout(&mut self, timeout: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .set_dispute_timeout(DurationSeconds::new(timeout))
+            .run();
+    }
+
+    pub fn set_protocol_fee(&mut self, fee_bps: u32) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .set_protocol_fee(fee_bps)
+            .run();
+    }
+
+    pub fn set_protocol_fee_expect_err(&mut self, fee_bps: u32, err_msg: &str) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .set_protocol_fee(fee_bps)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_treasury(&mut self, treasury: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .set_treasury(treasury.to_managed_address())
+            .run();
+    }
+
+    pub fn claim_fees(&mut self, from: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .claim_fees()
+            .run();
+    }
+
+    pub fn claim_fees_expect_err(&mut self, from: &multiversx_sc::types::TestAddress, err_msg: &str) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .claim_fees()
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn query_accumulated_fees(&mut self) -> Vec<Payment<StaticApi>> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_accumulated_fees()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn open_dispute(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .open_dispute(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn open_dispute_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
@@ -1577,25 +3532,31 @@ impl EscrowTestState {
             .from(*from)
             .to(ESCROW_SC_ADDRESS)
             .typed(EscrowProxy)
-            .release(ManagedBuffer::from(job_id))
+            .open_dispute(ManagedBuffer::from(job_id))
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
-    pub fn refund(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+    pub fn resolve_dispute(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver_amount: u64,
+    ) {
         self.world
             .tx()
             .from(*from)
             .to(ESCROW_SC_ADDRESS)
             .typed(EscrowProxy)
-            .refund(ManagedBuffer::from(job_id))
+            .resolve_dispute(ManagedBuffer::from(job_id), BigUint::from(receiver_amount))
             .run();
     }
 
-    pub fn refund_expect_err(
+    pub fn resolve_dispute_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
         job_id: &[u8],
+        receiver_amount: u64,
         err_msg: &str,
     ) {
         self.world
@@ -1603,11 +3564,88 @@ impl EscrowTestState {
             .from(*from)
             .to(ESCROW_SC_ADDRESS)
             .typed(EscrowProxy)
-            .refund(ManagedBuffer::from(job_id))
+            .resolve_dispute(ManagedBuffer::from(job_id), BigUint::from(receiver_amount))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn resolve_expired_dispute(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .resolve_expired_dispute(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn resolve_expired_dispute_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .resolve_expired_dispute(ManagedBuffer::from(job_id))
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
+    pub fn upgrade_escrow(&mut self, job_ids: &[&[u8]]) {
+        let mut args = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for job_id in job_ids {
+            args.push(ManagedBuffer::from(*job_id));
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .upgrade(args)
+            .code(ESCROW_CODE)
+            .run();
+    }
+
+    /// Whitebox helper: store an active EGLD escrow in the layout used before milestones.
+    pub fn store_legacy_escrow(
+        &mut self,
+        job_id: &[u8],
+        employer: &multiversx_sc::types::TestAddress,
+        receiver: &multiversx_sc::types::TestAddress,
+        deadline: u64,
+        amount: u64,
+    ) {
+        let employer = employer.eval_to_array();
+        let receiver = receiver.eval_to_array();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .whitebox(escrow::contract_obj, |sc| {
+                let legacy = LegacyEscrowData::<DebugApi> {
+                    employer: ManagedAddress::from(employer),
+                    receiver: ManagedAddress::from(receiver),
+                    token_id: EgldOrEsdtTokenIdentifier::egld(),
+                    token_nonce: 0,
+                    amount: BigUint::from(amount),
+                    poa_hash: ManagedBuffer::from(b"legacy_poa"),
+                    deadline: TimestampSeconds::new(deadline),
+                    status: EscrowStatus::Active,
+                };
+                let mut raw = ManagedBuffer::new();
+                legacy.top_encode(&mut raw).unwrap();
+                sc.raw_escrow_data(&ManagedBuffer::from(job_id)).set(raw);
+            });
+    }
+
     // ── Escrow queries ──
 
     pub fn query_escrow(&mut self, job_id: &[u8]) -> EscrowData<StaticApi> {
@@ -1620,6 +3658,42 @@ impl EscrowTestState {
             .run()
     }
 
+    pub fn query_dispute(&mut self, job_id: &[u8]) -> Option<DisputeData<StaticApi>> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_dispute(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    pub fn query_escrow_remaining(&mut self, job_id: &[u8]) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_escrow_remaining(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_milestone(
+        &mut self,
+        job_id: &[u8],
+        milestone_index: u32,
+    ) -> Option<Milestone<StaticApi>> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_milestone(ManagedBuffer::from(job_id), milestone_index)
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
     pub fn query_is_job_verified(&mut self, job_id: &[u8]) -> bool {
         self.world
             .query()
@@ -78,11 +78,10 @@ mod cs {
 
         let agent_nonce = 1u64;
         let carol = interact.client.clone();
-        let worker = interact.worker.clone();
 
         interact.init_job(&carol, b"job-001", agent_nonce).await;
         interact
-            .submit_proof(&worker, b"job-001", b"proof-data-hash")
+            .submit_proof(&bob, b"job-001", b"proof-data-hash")
             .await;
 
         // Use validation_request + validation_response flow
@@ -137,12 +136,11 @@ mod cs {
 
         let agent_nonce = 1u64;
         let carol = interact.client.clone();
-        let worker = interact.worker.clone();
 
         // Job lifecycle
         interact.init_job(&carol, b"job-001", agent_nonce).await;
         interact
-            .submit_proof(&worker, b"job-001", b"proof-data-hash")
+            .submit_proof(&bob, b"job-001", b"proof-data-hash")
             .await;
 
         // Validation flow
@@ -250,10 +248,7 @@ mod cs {
         let carol = interact.client.clone();
         interact.init_job(&carol, b"job-001", 1u64).await;
 
-        let worker = interact.worker.clone();
-        interact
-            .submit_proof(&worker, b"job-001", b"proof-data")
-            .await;
+        interact.submit_proof(&bob, b"job-001", b"proof-data").await;
 
         // Non-owner (carol) tries to make a validation request — should fail
         interact
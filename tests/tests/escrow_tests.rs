@@ -1,8 +1,21 @@
-use escrow::storage::EscrowStatus;
+use escrow::storage::{EscrowStatus, MilestoneStatus};
 use multiversx_sc::types::{BigUint, ManagedAddress, ManagedBuffer};
 use multiversx_sc_scenario::api::StaticApi;
 use mx_8004_tests::{constants::*, setup::EscrowTestState};
 
+/// Registers the agent and opens `job_id` for it, so an escrow can be deposited.
+fn open_job(state: &mut EscrowTestState, job_id: &[u8]) {
+    state.register_agent(
+        &AGENT_OWNER,
+        b"EscrowAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state.init_job(&EMPLOYER, job_id, 1, None);
+}
+
 // ============================================
 // 1. Deploy Escrow
 // ============================================
@@ -21,6 +34,7 @@ fn test_deploy_escrow() {
 #[test]
 fn test_deposit_egld() {
     let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_egld_1");
 
     state.deposit_egld(
         &EMPLOYER,
@@ -49,6 +63,7 @@ fn test_deposit_egld() {
 #[test]
 fn test_deposit_esdt() {
     let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_esdt_1");
 
     state.deposit_esdt(
         &EMPLOYER,
@@ -94,6 +109,7 @@ fn test_deposit_zero_amount() {
 #[test]
 fn test_deposit_duplicate_job() {
     let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_dup");
 
     state.deposit_egld(
         &EMPLOYER,
@@ -137,7 +153,7 @@ fn test_release_verified() {
     state.init_job(&EMPLOYER, b"job_release", 1, None);
 
     // Submit proof
-    state.submit_proof(&WORKER, b"job_release", b"proof_data");
+    state.submit_proof(&AGENT_OWNER, b"job_release", b"proof_data");
 
     // Validation request + response (to get job to Verified status)
     state.validation_request(
@@ -194,7 +210,7 @@ fn test_release_not_verified() {
 
     // Init job but do NOT go through validation
     state.init_job(&EMPLOYER, b"job_not_verified", 1, None);
-    state.submit_proof(&WORKER, b"job_not_verified", b"proof_data");
+    state.submit_proof(&AGENT_OWNER, b"job_not_verified", b"proof_data");
 
     // Deposit into escrow
     state.deposit_egld(
@@ -215,11 +231,11 @@ fn test_release_not_verified() {
 }
 
 // ============================================
-// 8. Release Not Employer → Error
+// 8. Release by a Third Party
 // ============================================
 
 #[test]
-fn test_release_not_employer() {
+fn test_release_by_anyone() {
     let mut state = EscrowTestState::new();
 
     // Register agent
@@ -234,7 +250,7 @@ fn test_release_not_employer() {
 
     // Full validation flow to get verified status
     state.init_job(&EMPLOYER, b"job_not_emp", 1, None);
-    state.submit_proof(&WORKER, b"job_not_emp", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job_not_emp", b"proof");
     state.validation_request(
         &AGENT_OWNER,
         b"job_not_emp",
@@ -261,8 +277,16 @@ fn test_release_not_employer() {
         500_000,
     );
 
-    // Non-employer (CLIENT) tries to release → error
-    state.release_expect_err(&CLIENT, b"job_not_emp", "Only the employer can call this");
+    // Anyone (CLIENT here) can release verified work to the receiver
+    state.release(&CLIENT, b"job_not_emp");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 500_000);
+    assert_eq!(
+        state.query_escrow(b"job_not_emp").status,
+        EscrowStatus::Released
+    );
 }
 
 // ============================================
@@ -283,7 +307,7 @@ fn test_release_already_released() {
     );
 
     state.init_job(&EMPLOYER, b"job_double_rel", 1, None);
-    state.submit_proof(&WORKER, b"job_double_rel", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job_double_rel", b"proof");
     state.validation_request(
         &AGENT_OWNER,
         b"job_double_rel",
@@ -323,6 +347,7 @@ fn test_release_already_released() {
 #[test]
 fn test_refund_after_deadline() {
     let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_refund");
 
     // Set initial block timestamp to 100
     state.world.current_block().block_timestamp_seconds(100);
@@ -354,6 +379,7 @@ fn test_refund_after_deadline() {
 #[test]
 fn test_refund_before_deadline() {
     let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_early_refund");
 
     state.world.current_block().block_timestamp_seconds(100);
 
@@ -381,6 +407,7 @@ fn test_refund_before_deadline() {
 #[test]
 fn test_refund_already_refunded() {
     let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_double_ref");
 
     state.world.current_block().block_timestamp_seconds(100);
 
@@ -422,7 +449,22 @@ fn test_release_after_refund() {
     state.world.current_block().block_timestamp_seconds(100);
 
     state.init_job(&EMPLOYER, b"job_ref_then_rel", 1, None);
-    state.submit_proof(&WORKER, b"job_ref_then_rel", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job_ref_then_rel", b"proof");
+
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_ref_then_rel",
+        &AGENT_OWNER,
+        b"poa_hash",
+        200,
+        500_000,
+    );
+
+    // Refund first (deadline passed)
+    state.world.current_block().block_timestamp_seconds(201);
+    state.refund(&EMPLOYER, b"job_ref_then_rel");
+
+    // The job is verified only after the refund
     state.validation_request(
         &AGENT_OWNER,
         b"job_ref_then_rel",
@@ -439,19 +481,6 @@ fn test_release_after_refund() {
         b"approved",
     );
 
-    state.deposit_egld(
-        &EMPLOYER,
-        b"job_ref_then_rel",
-        &AGENT_OWNER,
-        b"poa_hash",
-        200,
-        500_000,
-    );
-
-    // Refund first (deadline passed)
-    state.world.current_block().block_timestamp_seconds(201);
-    state.refund(&EMPLOYER, b"job_ref_then_rel");
-
     // Try release after refund → already settled
     state.release_expect_err(&EMPLOYER, b"job_ref_then_rel", "Escrow already settled");
 }
@@ -476,7 +505,7 @@ fn test_refund_after_release() {
     state.world.current_block().block_timestamp_seconds(100);
 
     state.init_job(&EMPLOYER, b"job_rel_then_ref", 1, None);
-    state.submit_proof(&WORKER, b"job_rel_then_ref", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job_rel_then_ref", b"proof");
     state.validation_request(
         &AGENT_OWNER,
         b"job_rel_then_ref",
@@ -552,7 +581,7 @@ fn test_full_lifecycle_egld() {
 
     // 2. Init job + validation flow
     state.init_job(&EMPLOYER, b"lifecycle_egld", 1, None);
-    state.submit_proof(&WORKER, b"lifecycle_egld", b"proof_lc");
+    state.submit_proof(&AGENT_OWNER, b"lifecycle_egld", b"proof_lc");
     state.validation_request(
         &AGENT_OWNER,
         b"lifecycle_egld",
@@ -615,7 +644,7 @@ fn test_full_lifecycle_esdt() {
 
     // 2. Init job + validation flow
     state.init_job(&EMPLOYER, b"lifecycle_esdt", 1, None);
-    state.submit_proof(&WORKER, b"lifecycle_esdt", b"proof_esdt");
+    state.submit_proof(&AGENT_OWNER, b"lifecycle_esdt", b"proof_esdt");
     state.validation_request(
         &AGENT_OWNER,
         b"lifecycle_esdt",
@@ -656,3 +685,1140 @@ fn test_full_lifecycle_esdt() {
     let escrow = state.query_escrow(b"lifecycle_esdt");
     assert_eq!(escrow.status, EscrowStatus::Released);
 }
+
+// ============================================
+// 19. Deposit rejected while agent is paused
+// ============================================
+
+#[test]
+fn test_deposit_agent_paused() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"PausedAgent",
+        b"https://paused.agent.com",
+        b"pubkey_p",
+        vec![],
+        vec![],
+    );
+    state.init_job(&EMPLOYER, b"job_paused", 1, None);
+
+    state.pause_agent(&AGENT_OWNER, 1);
+    state.deposit_egld_expect_err(
+        &EMPLOYER,
+        b"job_paused",
+        &AGENT_OWNER,
+        b"poa_paused",
+        1_000_000,
+        500_000,
+        "Agent is paused",
+    );
+}
+
+// ============================================
+// 20. Rejected job: no release, refund before deadline
+// ============================================
+
+#[test]
+fn test_rejected_job_refund_before_deadline() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"RejectedAgent",
+        b"https://rejected.agent.com",
+        b"pubkey_r",
+        vec![],
+        vec![],
+    );
+    state.init_job(&EMPLOYER, b"job_rejected", 1, None);
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_rejected",
+        &AGENT_OWNER,
+        b"poa_rejected",
+        1_000_000,
+        500_000,
+    );
+
+    // Still open: the deadline applies
+    state.refund_expect_err(&EMPLOYER, b"job_rejected", "Deadline has not passed yet");
+
+    state.submit_proof(&AGENT_OWNER, b"job_rejected", b"proof_r");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_rejected",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"rejected_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"rejected_hash",
+        10,
+        b"https://resp.uri",
+        b"rejected_resp",
+        b"failed",
+    );
+    assert!(!state.query_is_job_verified(b"job_rejected"));
+
+    state.release_expect_err(
+        &EMPLOYER,
+        b"job_rejected",
+        "Job must be verified before release",
+    );
+    state.refund(&EMPLOYER, b"job_rejected");
+
+    let escrow = state.query_escrow(b"job_rejected");
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+}
+
+// ============================================
+// 21. Cancelled job: refund before deadline
+// ============================================
+
+#[test]
+fn test_cancelled_job_refund_before_deadline() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"CancelledAgent",
+        b"https://cancelled.agent.com",
+        b"pubkey_c",
+        vec![],
+        vec![],
+    );
+    state.init_job(&EMPLOYER, b"job_cancelled", 1, None);
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_cancelled",
+        &AGENT_OWNER,
+        b"poa_cancelled",
+        1_000_000,
+        500_000,
+    );
+    state.refund_expect_err(&EMPLOYER, b"job_cancelled", "Deadline has not passed yet");
+
+    state.cancel_job(&EMPLOYER, b"job_cancelled");
+    state.release_expect_err(
+        &EMPLOYER,
+        b"job_cancelled",
+        "Job must be verified before release",
+    );
+    state.refund(&EMPLOYER, b"job_cancelled");
+
+    let escrow = state.query_escrow(b"job_cancelled");
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+}
+
+// ============================================
+// 22. Milestone escrow: each milestone released on its own validation
+// ============================================
+
+fn open_milestone_job(state: &mut EscrowTestState, job_id: &[u8]) {
+    state.register_agent(
+        &AGENT_OWNER,
+        b"MilestoneAgent",
+        b"https://milestone.agent.com",
+        b"pubkey_m",
+        vec![],
+        vec![],
+    );
+    state.init_job(&EMPLOYER, job_id, 1, None);
+    state.enable_job_milestones(&EMPLOYER, job_id);
+    state.submit_proof(&AGENT_OWNER, job_id, b"proof_m");
+}
+
+#[test]
+fn test_milestone_escrow_partial_releases() {
+    let mut state = EscrowTestState::new();
+    open_milestone_job(&mut state, b"job_ms");
+    state.deposit_milestones_egld(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        500_000,
+        &[(200_000, b"deliverable_1"), (300_000, b"deliverable_2")],
+    );
+
+    let escrow = state.query_escrow(b"job_ms");
+    assert_eq!(escrow.milestones.len(), 2);
+    assert_eq!(
+        state.query_escrow_remaining(b"job_ms"),
+        BigUint::from(500_000u64)
+    );
+
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_ms",
+        &VALIDATOR,
+        b"https://val.uri/1",
+        b"deliverable_1",
+    );
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_ms",
+        &VALIDATOR,
+        b"https://val.uri/2",
+        b"deliverable_2",
+    );
+
+    // The second deliverable has not been validated yet
+    state.release_milestone_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        1,
+        "Deliverable must pass validation for this job before release",
+    );
+
+    state.validation_response(
+        &VALIDATOR,
+        b"deliverable_1",
+        90,
+        b"https://resp.uri/1",
+        b"resp_1",
+        b"approved",
+    );
+    state.release_milestone(&EMPLOYER, b"job_ms", 0);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 200_000);
+    assert_eq!(
+        state.query_escrow_remaining(b"job_ms"),
+        BigUint::from(300_000u64)
+    );
+    assert_eq!(
+        state.query_milestone(b"job_ms", 0).unwrap().status,
+        MilestoneStatus::Released
+    );
+    assert_eq!(state.query_escrow(b"job_ms").status, EscrowStatus::Active);
+    state.release_milestone_expect_err(&EMPLOYER, b"job_ms", 0, "Milestone already settled");
+
+    state.validation_response(
+        &VALIDATOR,
+        b"deliverable_2",
+        80,
+        b"https://resp.uri/2",
+        b"resp_2",
+        b"approved",
+    );
+    state.release_milestone(&EMPLOYER, b"job_ms", 1);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 500_000);
+    assert_eq!(state.query_escrow_remaining(b"job_ms"), BigUint::zero());
+    assert_eq!(state.query_escrow(b"job_ms").status, EscrowStatus::Released);
+}
+
+// ============================================
+// 23. Milestone escrow: release checks
+// ============================================
+
+#[test]
+fn test_milestone_release_checks() {
+    let mut state = EscrowTestState::new();
+    open_milestone_job(&mut state, b"job_ms");
+    state.init_job(&EMPLOYER, b"job_other", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_other", b"proof_o");
+    state.deposit_milestones_egld(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        300_000,
+        &[
+            (100_000, b"deliverable_1"),
+            (100_000, b"deliverable_2"),
+            (100_000, b"deliverable_3"),
+        ],
+    );
+
+    state.release_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        "Milestone escrows are released one milestone at a time",
+    );
+    state.release_milestone_expect_err(&EMPLOYER, b"job_ms", 3, "Milestone not found");
+
+    // Rejected by the validator
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_ms",
+        &VALIDATOR,
+        b"https://val.uri/1",
+        b"deliverable_1",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"deliverable_1",
+        10,
+        b"https://resp.uri/1",
+        b"resp_1",
+        b"failed",
+    );
+    state.release_milestone_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        0,
+        "Deliverable must pass validation for this job before release",
+    );
+
+    // Passed, but for another job
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_other",
+        &VALIDATOR,
+        b"https://val.uri/2",
+        b"deliverable_2",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"deliverable_2",
+        90,
+        b"https://resp.uri/2",
+        b"resp_2",
+        b"approved",
+    );
+    state.release_milestone_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        1,
+        "Deliverable must pass validation for this job before release",
+    );
+
+    // Lump-sum escrows have no milestones
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_other",
+        &AGENT_OWNER,
+        b"poa",
+        1_000_000,
+        1_000,
+    );
+    state.release_milestone_expect_err(&EMPLOYER, b"job_other", 0, "Escrow has no milestones");
+}
+
+// ============================================
+// 24. Milestone escrow: deposit validation
+// ============================================
+
+#[test]
+fn test_milestone_deposit_validation() {
+    let mut state = EscrowTestState::new();
+
+    state.deposit_milestones_egld_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        500_000,
+        &[],
+        "At least one milestone is required",
+    );
+    state.deposit_milestones_egld_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        500_000,
+        &[(200_000, b"deliverable_1"), (200_000, b"deliverable_2")],
+        "Milestone amounts must add up to the deposit",
+    );
+    state.deposit_milestones_egld_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        500_000,
+        &[(500_000, b"deliverable_1"), (0, b"deliverable_2")],
+        "Milestone amount must be greater than zero",
+    );
+    state.deposit_milestones_egld_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        500_000,
+        &[(200_000, b"deliverable_1"), (300_000, b"deliverable_1")],
+        "Deliverable hash used by more than one milestone",
+    );
+}
+
+// ============================================
+// 25. Milestone escrow: refund of the pending milestones
+// ============================================
+
+#[test]
+fn test_milestone_refund_pending() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    open_milestone_job(&mut state, b"job_ms");
+    state.deposit_milestones_egld(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        200,
+        500_000,
+        &[(200_000, b"deliverable_1"), (300_000, b"deliverable_2")],
+    );
+
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_ms",
+        &VALIDATOR,
+        b"https://val.uri/1",
+        b"deliverable_1",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"deliverable_1",
+        90,
+        b"https://resp.uri/1",
+        b"resp_1",
+        b"approved",
+    );
+    state.release_milestone(&EMPLOYER, b"job_ms", 0);
+
+    state.world.current_block().block_timestamp_seconds(201);
+    state.refund(&CLIENT, b"job_ms");
+    state
+        .world
+        .check_account(EMPLOYER)
+        .balance(10_000_000_000u64 - 200_000);
+
+    let escrow = state.query_escrow(b"job_ms");
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.milestones.get(0).status, MilestoneStatus::Released);
+    assert_eq!(escrow.milestones.get(1).status, MilestoneStatus::Refunded);
+    assert_eq!(state.query_escrow_remaining(b"job_ms"), BigUint::zero());
+    assert!(state.query_milestone(b"job_ms", 2).is_none());
+}
+
+// ============================================
+// 26. Upgrade migrates escrows stored before milestones
+// ============================================
+
+#[test]
+fn test_upgrade_migrates_legacy_escrow() {
+    let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_ms");
+    state.enable_job_milestones(&EMPLOYER, b"job_ms");
+    state.store_legacy_escrow(b"job_legacy", &EMPLOYER, &AGENT_OWNER, 1_000_000, 500_000);
+    state.deposit_milestones_egld(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        500_000,
+        &[(500_000, b"deliverable_1")],
+    );
+
+    // Running the migration twice, or on current records, changes nothing
+    state.upgrade_escrow(&[b"job_legacy", b"job_ms", b"job_unknown"]);
+    state.upgrade_escrow(&[b"job_legacy"]);
+
+    let escrow = state.query_escrow(b"job_legacy");
+    assert_eq!(escrow.amount, BigUint::<StaticApi>::from(500_000u64));
+    assert_eq!(escrow.status, EscrowStatus::Active);
+    assert!(escrow.milestones.is_empty());
+    assert_eq!(
+        state.query_escrow_remaining(b"job_legacy"),
+        BigUint::from(500_000u64)
+    );
+    assert_eq!(state.query_escrow(b"job_ms").milestones.len(), 1);
+}
+
+// ============================================
+// 27. Dispute split by an arbiter
+// ============================================
+
+fn verify_job(state: &mut EscrowTestState, job_id: &[u8], request_hash: &[u8]) {
+    state.validation_request(
+        &AGENT_OWNER,
+        job_id,
+        &VALIDATOR,
+        b"https://val.uri",
+        request_hash,
+    );
+    state.validation_response(
+        &VALIDATOR,
+        request_hash,
+        90,
+        b"https://resp.uri",
+        b"resp_hash",
+        b"approved",
+    );
+}
+
+#[test]
+fn test_dispute_resolved_by_arbiter() {
+    let mut state = EscrowTestState::new();
+    state.add_arbiter(&ARBITER);
+    open_job(&mut state, b"job_dispute");
+    state.submit_proof(&AGENT_OWNER, b"job_dispute", b"proof");
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_dispute",
+        &AGENT_OWNER,
+        b"poa",
+        1_000_000,
+        500_000,
+    );
+    verify_job(&mut state, b"job_dispute", b"dispute_hash");
+
+    // Only the parties can open a dispute
+    state.open_dispute_expect_err(
+        &CLIENT,
+        b"job_dispute",
+        "Only the employer or the receiver can call this",
+    );
+    state.open_dispute(&AGENT_OWNER, b"job_dispute");
+    let dispute = state.query_dispute(b"job_dispute").unwrap();
+    assert_eq!(dispute.opened_by, AGENT_OWNER.to_managed_address());
+    assert_eq!(
+        state.query_escrow(b"job_dispute").status,
+        EscrowStatus::Disputed
+    );
+    assert_eq!(
+        state.query_escrow_remaining(b"job_dispute"),
+        BigUint::from(500_000u64)
+    );
+
+    // Frozen while disputed
+    state.release_expect_err(&EMPLOYER, b"job_dispute", "Escrow is under dispute");
+    state.refund_expect_err(&EMPLOYER, b"job_dispute", "Escrow is under dispute");
+    state.open_dispute_expect_err(&EMPLOYER, b"job_dispute", "Escrow is under dispute");
+
+    state.resolve_dispute_expect_err(
+        &EMPLOYER,
+        b"job_dispute",
+        0,
+        "Only an arbiter can call this",
+    );
+    state.resolve_dispute_expect_err(
+        &ARBITER,
+        b"job_dispute",
+        500_001,
+        "Receiver share exceeds the escrowed funds",
+    );
+    state.resolve_dispute(&ARBITER, b"job_dispute", 300_000);
+
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 300_000);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .balance(10_000_000_000u64 - 300_000);
+    assert_eq!(
+        state.query_escrow(b"job_dispute").status,
+        EscrowStatus::Resolved
+    );
+    assert!(state.query_dispute(b"job_dispute").is_none());
+    state.resolve_dispute_expect_err(&ARBITER, b"job_dispute", 0, "Escrow is not under dispute");
+}
+
+// ============================================
+// 28. Arbiter cannot resolve its own escrow
+// ============================================
+
+#[test]
+fn test_dispute_arbiter_is_party() {
+    let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_dispute");
+    state.add_arbiter(&EMPLOYER);
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_dispute",
+        &AGENT_OWNER,
+        b"poa",
+        1_000_000,
+        500_000,
+    );
+    state.open_dispute(&EMPLOYER, b"job_dispute");
+    state.resolve_dispute_expect_err(
+        &EMPLOYER,
+        b"job_dispute",
+        0,
+        "Arbiter cannot resolve a dispute it is party to",
+    );
+}
+
+// ============================================
+// 29. Dispute timeout favours the verified receiver
+// ============================================
+
+#[test]
+fn test_dispute_timeout_pays_verified_receiver() {
+    let mut state = EscrowTestState::new();
+    state.set_dispute_timeout(100);
+    state.world.current_block().block_timestamp_seconds(1_000);
+    open_job(&mut state, b"job_dispute");
+    state.submit_proof(&AGENT_OWNER, b"job_dispute", b"proof");
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_dispute",
+        &AGENT_OWNER,
+        b"poa",
+        5_000,
+        500_000,
+    );
+    verify_job(&mut state, b"job_dispute", b"dispute_hash");
+
+    // The employer disputes instead of releasing; the deadline passing changes nothing
+    state.open_dispute(&EMPLOYER, b"job_dispute");
+    state.resolve_expired_dispute_expect_err(
+        &CLIENT,
+        b"job_dispute",
+        "Dispute timeout has not passed yet",
+    );
+    state.world.current_block().block_timestamp_seconds(1_101);
+    state.resolve_expired_dispute(&CLIENT, b"job_dispute");
+
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 500_000);
+    assert_eq!(
+        state.query_escrow(b"job_dispute").status,
+        EscrowStatus::Resolved
+    );
+}
+
+// ============================================
+// 30. Dispute timeout refunds unverified work, per milestone
+// ============================================
+
+#[test]
+fn test_dispute_timeout_refunds_unverified() {
+    let mut state = EscrowTestState::new();
+    state.set_dispute_timeout(100);
+    state.world.current_block().block_timestamp_seconds(1_000);
+    open_milestone_job(&mut state, b"job_ms");
+    state.deposit_milestones_egld(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        500_000,
+        &[(200_000, b"deliverable_1"), (300_000, b"deliverable_2")],
+    );
+    verify_job(&mut state, b"job_ms", b"deliverable_1");
+
+    state.open_dispute(&AGENT_OWNER, b"job_ms");
+    state.release_milestone_expect_err(&EMPLOYER, b"job_ms", 0, "Escrow is under dispute");
+    state.world.current_block().block_timestamp_seconds(1_101);
+    state.resolve_expired_dispute(&CLIENT, b"job_ms");
+
+    // The validated deliverable is paid, the other one refunded
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 200_000);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .balance(10_000_000_000u64 - 200_000);
+    let escrow = state.query_escrow(b"job_ms");
+    assert_eq!(escrow.status, EscrowStatus::Resolved);
+    assert_eq!(escrow.milestones.get(0).status, MilestoneStatus::Released);
+    assert_eq!(escrow.milestones.get(1).status, MilestoneStatus::Refunded);
+
+    // Lump-sum escrow of an unverified job goes back to the employer
+    state.init_job(&EMPLOYER, b"job_lump", 1, None);
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_lump",
+        &AGENT_OWNER,
+        b"poa",
+        1_000_000,
+        1_000,
+    );
+    state.open_dispute(&AGENT_OWNER, b"job_lump");
+    state.world.current_block().block_timestamp_seconds(1_202);
+    state.resolve_expired_dispute(&CLIENT, b"job_lump");
+    state
+        .world
+        .check_account(EMPLOYER)
+        .balance(10_000_000_000u64 - 200_000);
+}
+
+// ============================================
+// 31. Verified work cannot be refunded after the deadline
+// ============================================
+
+#[test]
+fn test_refund_blocked_for_verified_work() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    open_job(&mut state, b"job_verified");
+    state.submit_proof(&AGENT_OWNER, b"job_verified", b"proof");
+    state.init_job(&EMPLOYER, b"job_ms", 1, None);
+    state.enable_job_milestones(&EMPLOYER, b"job_ms");
+    state.submit_proof(&AGENT_OWNER, b"job_ms", b"proof_ms");
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_verified",
+        &AGENT_OWNER,
+        b"poa",
+        200,
+        500_000,
+    );
+    state.deposit_milestones_egld(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        200,
+        500_000,
+        &[(200_000, b"deliverable_1"), (300_000, b"deliverable_2")],
+    );
+    verify_job(&mut state, b"job_verified", b"verified_hash");
+    verify_job(&mut state, b"job_ms", b"deliverable_1");
+    state.world.current_block().block_timestamp_seconds(201);
+
+    state.refund_expect_err(
+        &CLIENT,
+        b"job_verified",
+        "Verified work must be released, not refunded",
+    );
+    state.release(&AGENT_OWNER, b"job_verified");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 500_000);
+
+    // Milestones: a passed deliverable blocks the refund until it is released
+    state.refund_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        "Verified work must be released, not refunded",
+    );
+    state.release_milestone(&AGENT_OWNER, b"job_ms", 0);
+    state.refund(&CLIENT, b"job_ms");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 700_000);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .balance(10_000_000_000u64 - 700_000);
+}
+
+// ============================================
+// 32. Deposit is bound to the job and its agent
+// ============================================
+
+#[test]
+fn test_deposit_bound_to_job() {
+    let mut state = EscrowTestState::new();
+    open_job(&mut state, b"job_bound");
+
+    state.deposit_egld_expect_err(
+        &EMPLOYER,
+        b"job_unknown",
+        &AGENT_OWNER,
+        b"poa",
+        1_000_000,
+        500_000,
+        "Job not found",
+    );
+    state.deposit_egld_expect_err(
+        &CLIENT,
+        b"job_bound",
+        &AGENT_OWNER,
+        b"poa",
+        1_000_000,
+        500_000,
+        "Only the job employer can fund its escrow",
+    );
+    state.deposit_egld_expect_err(
+        &EMPLOYER,
+        b"job_bound",
+        &CLIENT,
+        b"poa",
+        1_000_000,
+        500_000,
+        "Receiver must be the owner of the job agent",
+    );
+
+    // The zero address lets the escrow pay the agent owner
+    state.deposit_egld_to_agent(&EMPLOYER, b"job_bound", b"poa", 1_000_000, 500_000);
+    let escrow = state.query_escrow(b"job_bound");
+    assert_eq!(escrow.employer, EMPLOYER.to_managed_address());
+    assert_eq!(escrow.receiver, AGENT_OWNER.to_managed_address());
+}
+
+// ============================================
+// 33. Protocol fee on releases, EGLD and ESDT
+// ============================================
+
+#[test]
+fn test_protocol_fee_on_release() {
+    let mut state = EscrowTestState::new();
+    state.set_protocol_fee_expect_err(1_001, "Protocol fee exceeds the maximum");
+    state.set_protocol_fee(100);
+
+    open_job(&mut state, b"job_egld");
+    state.init_job(&EMPLOYER, b"job_esdt", 1, None);
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_egld",
+        &AGENT_OWNER,
+        b"poa",
+        1_000_000,
+        500_000,
+    );
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_esdt",
+        &AGENT_OWNER,
+        b"poa",
+        1_000_000,
+        "USDC-abcdef",
+        0,
+        1_000,
+    );
+    assert_eq!(state.query_escrow(b"job_egld").protocol_fee_bps, 100);
+
+    // Raising the fee later does not touch escrows already funded
+    state.set_protocol_fee(1_000);
+    for job_id in [b"job_egld", b"job_esdt"] {
+        state.submit_proof(&AGENT_OWNER, job_id, b"proof");
+        verify_job(&mut state, job_id, job_id);
+        state.release(&AGENT_OWNER, job_id);
+    }
+
+    // 1% of each release stays in the escrow contract
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 495_000)
+        .esdt_balance(PAYMENT_TOKEN, 990u64);
+    let fees = state.query_accumulated_fees();
+    assert_eq!(fees.len(), 2);
+    assert_eq!(fees[0].amount.as_big_uint(), &BigUint::from(5_000u64));
+    assert_eq!(fees[1].amount.as_big_uint(), &BigUint::from(10u64));
+
+    state.set_treasury(&TREASURY);
+    state.claim_fees(&OWNER_ADDRESS);
+    state
+        .world
+        .check_account(TREASURY)
+        .balance(5_000u64)
+        .esdt_balance(PAYMENT_TOKEN, 10u64);
+    state
+        .world
+        .check_account(ESCROW_SC_ADDRESS)
+        .balance(0u64)
+        .esdt_balance(PAYMENT_TOKEN, 0u64);
+}
+
+// ============================================
+// 34. Cleaning old jobs cannot unlock a refund of verified work
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_keeps_verified_escrow() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    open_job(&mut state, b"job_clean");
+    state.submit_proof(&AGENT_OWNER, b"job_clean", b"proof");
+    state.deposit_egld(&EMPLOYER, b"job_clean", &AGENT_OWNER, b"poa", 200, 500_000);
+    verify_job(&mut state, b"job_clean", b"clean_hash");
+
+    // Past the escrow deadline and the 3-day cleanup age
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(100 + 4 * 24 * 60 * 60);
+    state.clean_old_jobs(vec![b"job_clean"]);
+    state.refund_expect_err(
+        &CLIENT,
+        b"job_clean",
+        "Verified work must be released, not refunded",
+    );
+    state.release(&AGENT_OWNER, b"job_clean");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 500_000);
+}
+
+// ============================================
+// 35. Arbiter resolution settles the pending milestones
+// ============================================
+
+#[test]
+fn test_dispute_resolved_by_arbiter_settles_milestones() {
+    let mut state = EscrowTestState::new();
+    state.add_arbiter(&ARBITER);
+    open_milestone_job(&mut state, b"job_ms");
+    state.deposit_milestones_egld(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000_000,
+        500_000,
+        &[(200_000, b"deliverable_1"), (300_000, b"deliverable_2")],
+    );
+    verify_job(&mut state, b"job_ms", b"deliverable_1");
+    state.release_milestone(&CLIENT, b"job_ms", 0);
+
+    state.open_dispute(&EMPLOYER, b"job_ms");
+    state.resolve_dispute(&ARBITER, b"job_ms", 100_000);
+
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 200_000 + 100_000);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .balance(10_000_000_000u64 - 300_000);
+    let escrow = state.query_escrow(b"job_ms");
+    assert_eq!(escrow.status, EscrowStatus::Resolved);
+    assert_eq!(escrow.milestones.get(0).status, MilestoneStatus::Released);
+    assert_eq!(escrow.milestones.get(1).status, MilestoneStatus::Resolved);
+    assert_eq!(state.query_escrow_remaining(b"job_ms"), BigUint::zero());
+}
+
+// ============================================
+// 36. Milestones validated one after the other
+// ============================================
+
+#[test]
+fn test_milestones_validated_in_sequence() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    open_job(&mut state, b"job_plain");
+    state.deposit_milestones_egld_expect_err(
+        &EMPLOYER,
+        b"job_plain",
+        &AGENT_OWNER,
+        1_000,
+        500_000,
+        &[(500_000, b"deliverable_1")],
+        "Milestone escrows need a job with milestones enabled",
+    );
+
+    state.init_job(&EMPLOYER, b"job_ms", 1, None);
+    state.enable_job_milestones(&EMPLOYER, b"job_ms");
+    state.deposit_egld_expect_err(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        500_000,
+        "Jobs with milestones enabled need a milestone escrow",
+    );
+    state.deposit_milestones_egld(
+        &EMPLOYER,
+        b"job_ms",
+        &AGENT_OWNER,
+        1_000,
+        600_000,
+        &[
+            (100_000, b"deliverable_1"),
+            (200_000, b"deliverable_2"),
+            (300_000, b"deliverable_3"),
+        ],
+    );
+
+    // Milestone 1 is submitted, validated and released before milestone 2 is requested
+    state.submit_proof(&AGENT_OWNER, b"job_ms", b"proof_1");
+    verify_job(&mut state, b"job_ms", b"deliverable_1");
+    assert!(!state.query_is_job_verified(b"job_ms"));
+    state.release_milestone(&CLIENT, b"job_ms", 0);
+
+    // A rejected deliverable neither closes the job nor unlocks an early refund
+    state.submit_proof(&AGENT_OWNER, b"job_ms", b"proof_2");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_ms",
+        &VALIDATOR,
+        b"https://val.uri/2",
+        b"deliverable_2",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"deliverable_2",
+        10,
+        b"https://resp.uri/2",
+        b"resp_2",
+        b"failed",
+    );
+    state.refund_expect_err(&EMPLOYER, b"job_ms", "Deadline has not passed yet");
+
+    state.submit_proof(&AGENT_OWNER, b"job_ms", b"proof_3");
+    verify_job(&mut state, b"job_ms", b"deliverable_3");
+    state.release_milestone(&CLIENT, b"job_ms", 2);
+    state.release_milestone_expect_err(
+        &CLIENT,
+        b"job_ms",
+        1,
+        "Deliverable must pass validation for this job before release",
+    );
+
+    // Only the failed milestone goes back to the employer
+    state.world.current_block().block_timestamp_seconds(1_001);
+    state.refund(&CLIENT, b"job_ms");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 400_000);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .balance(10_000_000_000u64 - 400_000);
+    let escrow = state.query_escrow(b"job_ms");
+    assert_eq!(escrow.milestones.get(0).status, MilestoneStatus::Released);
+    assert_eq!(escrow.milestones.get(1).status, MilestoneStatus::Refunded);
+    assert_eq!(escrow.milestones.get(2).status, MilestoneStatus::Released);
+}
+
+// ============================================
+// 37. A job id initialised again does not unlock someone else's escrow
+// ============================================
+
+#[test]
+fn test_reinitialised_job_cannot_refund_escrow() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    open_job(&mut state, b"job_reused");
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_reused",
+        &AGENT_OWNER,
+        b"poa",
+        10 * 24 * 60 * 60,
+        500_000,
+    );
+    let escrow = state.query_escrow(b"job_reused");
+    assert_eq!(escrow.agent_nonce, 1);
+    assert_eq!(escrow.job_creation_timestamp.as_u64_millis(), 100_000);
+    state.submit_proof(&AGENT_OWNER, b"job_reused", b"proof");
+
+    // A third party wipes the job, opens the same id as its own employer and cancels it
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(100 + 4 * 24 * 60 * 60);
+    state.clean_old_jobs(vec![b"job_reused"]);
+    state.init_job(&CLIENT, b"job_reused", 1, None);
+    state.cancel_job(&CLIENT, b"job_reused");
+
+    state.refund_expect_err(&CLIENT, b"job_reused", "Deadline has not passed yet");
+    state.release_expect_err(&CLIENT, b"job_reused", "Job not found");
+
+    // The original employer still gets the funds back once the deadline passes
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(10 * 24 * 60 * 60 + 1);
+    state.refund(&CLIENT, b"job_reused");
+    state
+        .world
+        .check_account(EMPLOYER)
+        .balance(10_000_000_000u64);
+    assert_eq!(
+        state.query_escrow(b"job_reused").status,
+        EscrowStatus::Refunded
+    );
+}
+
+// ============================================
+// 38. Early refund only trusts the rejection of the escrow's own job
+// ============================================
+
+#[test]
+fn test_reinitialised_job_rejection_cannot_refund_escrow() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    open_job(&mut state, b"job_reused");
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_reused",
+        &AGENT_OWNER,
+        b"poa",
+        10 * 24 * 60 * 60,
+        500_000,
+    );
+
+    // After the cleanup, a third party reopens the id for its own agent and rejects it
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(100 + 4 * 24 * 60 * 60);
+    state.clean_old_jobs(vec![b"job_reused"]);
+    state.register_agent(
+        &CLIENT,
+        b"SelfRejectAgent",
+        b"https://client.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_reused", 2, None);
+    state.submit_proof(&CLIENT, b"job_reused", b"proof");
+    state.validation_request(
+        &CLIENT,
+        b"job_reused",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"self_reject_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"self_reject_hash",
+        0,
+        b"https://resp.uri",
+        b"resp",
+        b"failed",
+    );
+
+    state.refund_expect_err(&CLIENT, b"job_reused", "Deadline has not passed yet");
+    assert_eq!(
+        state.query_escrow(b"job_reused").status,
+        EscrowStatus::Active
+    );
+}
+
+// ============================================
+// 39. Jobs with funds in escrow are not cleaned
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_skips_active_escrow() {
+    let mut state = EscrowTestState::new();
+    state.link_validation_to_escrow();
+    state.world.current_block().block_timestamp_seconds(100);
+    open_job(&mut state, b"job_escrowed");
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_escrowed",
+        &AGENT_OWNER,
+        b"poa",
+        10 * 24 * 60 * 60,
+        500_000,
+    );
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(100 + 4 * 24 * 60 * 60);
+    state.clean_old_jobs(vec![b"job_escrowed"]);
+
+    // The agent can still deliver and get paid
+    state.submit_proof(&AGENT_OWNER, b"job_escrowed", b"proof");
+    verify_job(&mut state, b"job_escrowed", b"escrowed_hash");
+    state.release(&CLIENT, b"job_escrowed");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 500_000);
+}
@@ -1,8 +1,23 @@
-use common::structs::JobStatus;
+use common::structs::{
+    AgentDetails, AgentStatus, FeesClaimedEventData, JobStatus, PERMISSION_EDIT_METADATA,
+    PERMISSION_EDIT_SERVICES, PERMISSION_REQUEST_VALIDATION, PERMISSION_SUBMIT_PROOF,
+};
+use multiversx_sc::codec::top_encode_to_vec_u8_or_panic;
 use multiversx_sc::proxy_imports::OptionalValue;
-use multiversx_sc::types::{BigUint, ManagedAddress, ManagedBuffer};
+use multiversx_sc::types::{
+    BigUint, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedBuffer, ManagedVec, NonZeroBigUint,
+    Payment, TimestampSeconds, TokenId,
+};
 use multiversx_sc_scenario::api::StaticApi;
-use mx_8004_tests::{constants::*, setup::AgentTestState};
+use mx_8004_tests::{
+    constants::*,
+    setup::{AgentTestState, agent_public_key, find_log, keccak256, sign_proof, sign_proof_for},
+};
+use proxies::validation_registry_proxy::{
+    HeldPaymentEventData, JobCancelledEventData, JobStatusChangedEventData,
+    ValidationRequestEventData, ValidationRequestExpiredEventData, ValidationResponseEventData,
+    ValidatorStakeEventData, ValidatorStatus,
+};
 
 // ============================================
 // 1. Deploy
@@ -89,12 +104,9 @@ fn test_register_agent_duplicate() {
 }
 
 // ============================================
-// 4. Update Agent (requires NFT transfer + Ed25519 sig)
+// 4. Update Agent — Not Agent Owner
 // ============================================
 
-// updateAgent requires Ed25519 signature verification at VM level.
-// We test the error paths (wrong NFT owner) here. Full Ed25519 flow needs chain-simulator.
-
 #[test]
 fn test_update_agent_not_owner() {
     let mut state = AgentTestState::new();
@@ -108,15 +120,13 @@ fn test_update_agent_not_owner() {
     );
 
     // CLIENT tries to update agent 1 (owned by AGENT_OWNER) -> error
-    // CLIENT doesn't have the NFT, so we need to give them one to even try
-    // Instead, test with wrong NFT token by trying from a non-owner
     state.update_agent_expect_err(
         &CLIENT,
         1,
         b"NewName",
         b"https://new.uri",
         b"newpubkey",
-        "insufficient funds",
+        "Only the agent owner can perform this action",
     );
 }
 
@@ -322,8 +332,8 @@ fn test_submit_proof() {
     );
 
     state.init_job(&CLIENT, b"job_proof", 1, None);
-    // submit_proof is open — anyone (e.g. WORKER agent) can call it
-    state.submit_proof(&WORKER, b"job_proof", b"proof_data_here");
+    // submit_proof is restricted to the agent owner (or an authorised operator)
+    state.submit_proof(&AGENT_OWNER, b"job_proof", b"proof_data_here");
 
     let job = state.query_job_data(b"job_proof");
     if let OptionalValue::Some(data) = job {
@@ -352,7 +362,7 @@ fn test_validation_request() {
     );
 
     state.init_job(&CLIENT, b"job_vr", 1, None);
-    state.submit_proof(&WORKER, b"job_vr", b"proof123");
+    state.submit_proof(&AGENT_OWNER, b"job_vr", b"proof123");
 
     // Agent owner requests validation
     state.validation_request(
@@ -386,7 +396,7 @@ fn test_validation_request_not_owner() {
     );
 
     state.init_job(&CLIENT, b"job_notowner", 1, None);
-    state.submit_proof(&WORKER, b"job_notowner", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job_notowner", b"proof");
 
     // CLIENT (not agent owner) tries to request validation
     state.validation_request_expect_err(
@@ -416,7 +426,7 @@ fn test_validation_response() {
     );
 
     state.init_job(&CLIENT, b"job_resp", 1, None);
-    state.submit_proof(&WORKER, b"job_resp", b"proof123");
+    state.submit_proof(&AGENT_OWNER, b"job_resp", b"proof123");
     state.validation_request(
         &AGENT_OWNER,
         b"job_resp",
@@ -453,7 +463,7 @@ fn test_validation_response_not_validator() {
     );
 
     state.init_job(&CLIENT, b"job_nv", 1, None);
-    state.submit_proof(&WORKER, b"job_nv", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job_nv", b"proof");
     state.validation_request(
         &AGENT_OWNER,
         b"job_nv",
@@ -525,7 +535,7 @@ fn test_full_feedback_flow() {
     );
 
     state.init_job(&CLIENT, b"job_fb", 1, None);
-    state.submit_proof(&WORKER, b"job_fb", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job_fb", b"proof");
 
     // ERC-8004: employer (CLIENT) submits feedback directly — no authorization needed
     state.give_feedback_simple(&CLIENT, b"job_fb", 1, 80);
@@ -557,7 +567,7 @@ fn test_feedback_guards() {
     );
 
     state.init_job(&CLIENT, b"job_guard", 1, None);
-    state.submit_proof(&WORKER, b"job_guard", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job_guard", b"proof");
 
     // Non-employer tries to submit feedback -> error
     state.give_feedback_simple_expect_err(
@@ -603,7 +613,7 @@ fn test_full_lifecycle() {
     state.init_job_with_payment(&CLIENT, b"lifecycle_job", 1, 1, "USDC-abcdef", 0, 200);
 
     // 3. Submit proof (WORKER = agent)
-    state.submit_proof(&WORKER, b"lifecycle_job", b"proof_lifecycle");
+    state.submit_proof(&AGENT_OWNER, b"lifecycle_job", b"proof_lifecycle");
 
     // 4. Validation request (agent owner)
     state.validation_request(
@@ -693,11 +703,11 @@ fn test_query_agent_token_id() {
 }
 
 // ============================================
-// 22. Query Agents (BiDi mapper)
+// 22. Query Agents by Owner
 // ============================================
 
 #[test]
-fn test_query_agents_bidi() {
+fn test_query_agents_by_owner() {
     let mut state = AgentTestState::new();
     state.register_agent(
         &AGENT_OWNER,
@@ -708,12 +718,9 @@ fn test_query_agents_bidi() {
         vec![],
     );
 
-    let agents = state.query_agents();
-    let entries: Vec<_> = agents.into_iter().collect();
-    assert_eq!(entries.len(), 1);
-    let (nonce, addr) = entries[0].clone().into_tuple();
-    assert_eq!(nonce, 1u64);
-    assert_eq!(addr, AGENT_OWNER.to_managed_address());
+    assert_eq!(state.query_agents_by_owner(&AGENT_OWNER, 0, 10), vec![1u64]);
+    assert_eq!(state.query_agent_count_by_owner(&AGENT_OWNER), 1);
+    assert!(state.query_agents_by_owner(&CLIENT, 0, 10).is_empty());
 }
 
 // ============================================
@@ -1012,7 +1019,7 @@ fn test_remove_service_configs_not_owner() {
 }
 
 // ============================================
-// 36. Submit Proof — Agent Owner also allowed
+// 36. Submit Proof — Agent Owner allowed
 // ============================================
 
 #[test]
@@ -1028,7 +1035,7 @@ fn test_submit_proof_agent_owner() {
     );
 
     state.init_job(&CLIENT, b"job-owner-proof", 1, None);
-    // Agent owner can also call submit_proof
+    // Agent owner can call submit_proof
     state.submit_proof(&AGENT_OWNER, b"job-owner-proof", b"proof_from_owner");
 
     let job = state.query_job_data(b"job-owner-proof");
@@ -1078,7 +1085,7 @@ fn test_give_feedback_simple_not_employer() {
     );
 
     state.init_job(&CLIENT, b"job-wrong-caller", 1, None);
-    state.submit_proof(&WORKER, b"job-wrong-caller", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job-wrong-caller", b"proof");
 
     // WORKER (not employer) tries to submit feedback
     state.give_feedback_simple_expect_err(
@@ -1107,7 +1114,7 @@ fn test_append_response_permissionless() {
     );
 
     state.init_job(&CLIENT, b"job-resp", 1, None);
-    state.submit_proof(&WORKER, b"job-resp", b"proof");
+    state.submit_proof(&AGENT_OWNER, b"job-resp", b"proof");
 
     // ERC-8004: anyone can append response — CLIENT can do it
     state.append_response(&CLIENT, b"job-resp", b"https://response.uri");
@@ -1124,6 +1131,7 @@ fn test_append_response_permissionless() {
 // ============================================
 
 #[test]
+#[allow(clippy::identity_op)]
 fn test_clean_old_jobs_not_old_enough() {
     let mut state = AgentTestState::new();
     state.register_agent(
@@ -1153,11 +1161,11 @@ fn test_clean_old_jobs_not_old_enough() {
 }
 
 // ============================================
-// 41. Update Agent — Invalid NFT (wrong nonce)
+// 41. Update Agent — Unknown Nonce
 // ============================================
 
 #[test]
-fn test_update_agent_invalid_nft() {
+fn test_update_agent_unknown_nonce() {
     let mut state = AgentTestState::new();
     state.register_agent(
         &AGENT_OWNER,
@@ -1168,14 +1176,14 @@ fn test_update_agent_invalid_nft() {
         vec![],
     );
 
-    // Try updating with wrong NFT nonce (2 doesn't exist)
+    // Try updating an agent nonce that was never minted
     state.update_agent_expect_err(
         &AGENT_OWNER,
         2,
         b"NewName",
         b"https://new.uri",
         b"newpubkey",
-        "insufficient funds",
+        "Agent not found",
     );
 }
 
@@ -1363,7 +1371,7 @@ fn test_is_job_verified_view() {
     state.validation_response(
         &VALIDATOR,
         b"req-verify-view",
-        1,
+        90,
         b"https://oracle.example.com/result",
         b"resp-verify-view",
         b"approved",
@@ -1458,76 +1466,66 @@ fn test_multi_job_reputation_average() {
 }
 
 // ============================================
-// 50. submit_proof_with_nft — Happy Path
+// 50. Soulbound — NFT stays custodied by the registry
 // ============================================
 
-// NOTE: submit_proof_with_nft uses storage_mapper_from_address to cross-read the
-// identity-registry's agentTokenId. The RustVM test environment fully supports this
-// because both contracts share the same test world.
-
 #[test]
-fn test_submit_proof_with_nft_happy_path() {
+fn test_agent_nft_custodied_by_registry() {
     let mut state = AgentTestState::new();
     state.register_agent(
-        &OWNER_ADDRESS,
-        b"NFTProofBot",
+        &AGENT_OWNER,
+        b"SoulboundBot",
         b"https://example.com/manifest",
-        &[0u8; 32],
-        vec![(b"type", b"worker")],
+        b"pubkey123",
+        vec![],
         vec![],
     );
-    state.init_job(&OWNER_ADDRESS, b"job_nft_proof", 1, None);
 
-    // Owner holds AGENT-abcdef nonce=1 after register_agent
-    state.submit_proof_with_nft(
-        &OWNER_ADDRESS,
-        b"job_nft_proof",
-        b"nft-proof-hash",
-        &AGENT_TOKEN,
-        1,
-    );
-
-    // Verify proof was stored — job should be Pending after proof
-    let job_data = state.query_job_data(b"job_nft_proof");
-    match job_data {
-        OptionalValue::Some(job) => {
-            assert_eq!(
-                job.proof,
-                ManagedBuffer::<StaticApi>::from(b"nft-proof-hash")
-            );
-        }
-        OptionalValue::None => panic!("Job data should exist after submit_proof_with_nft"),
-    }
+    // The identity NFT is held by the registry, never by the owner's wallet
+    state
+        .world
+        .check_account(IDENTITY_SC_ADDRESS)
+        .esdt_nft_balance_and_attributes(
+            AGENT_TOKEN,
+            1,
+            1u64,
+            AgentDetails::<StaticApi> {
+                name: ManagedBuffer::from(b"SoulboundBot"),
+                public_key: ManagedBuffer::from(b"pubkey123"),
+            },
+        );
+
+    let owner = state.query_agent_owner(1);
+    assert_eq!(owner, AGENT_OWNER.to_managed_address());
 }
 
 // ============================================
-// 51. submit_proof_with_nft — Nonexistent Job
+// 51. Soulbound — plain ESDT transfer cannot detach identity
 // ============================================
 
-// NOTE: Wrong-token and wrong-nonce error paths are tested via chain-sim (Suite Q/S)
-// because the RustVM validates ESDT balances before contract execution,
-// making it impossible to send tokens the caller doesn't hold.
-
 #[test]
-fn test_submit_proof_with_nft_nonexistent_job() {
+fn test_agent_nft_plain_transfer_rejected() {
     let mut state = AgentTestState::new();
     state.register_agent(
-        &OWNER_ADDRESS,
-        b"NFTErrBot",
+        &AGENT_OWNER,
+        b"SoulboundBot",
         b"https://example.com/manifest",
-        &[0u8; 32],
-        vec![(b"type", b"worker")],
+        b"pubkey123",
+        vec![],
         vec![],
     );
 
-    // Try to submit proof for a job that doesn't exist
-    state.submit_proof_with_nft_expect_err(
-        &OWNER_ADDRESS,
-        b"nonexistent_job",
-        b"nft-proof-hash",
-        &AGENT_TOKEN,
+    // Owner has nothing to send — the wallet never held the NFT
+    state.transfer_agent_nft_expect_err(&AGENT_OWNER, &CLIENT, 1, "insufficient funds");
+
+    // Ownership recorded in `agents` is unchanged, and CLIENT cannot act as owner
+    let owner = state.query_agent_owner(1);
+    assert_eq!(owner, AGENT_OWNER.to_managed_address());
+    state.set_metadata_expect_err(
+        &CLIENT,
         1,
-        "Job not found",
+        vec![(b"key", b"value")],
+        "Only the agent owner can perform this action",
     );
 }
 
@@ -1658,3 +1656,3013 @@ fn test_register_agent_token_not_issued() {
         "Token not issued",
     );
 }
+
+// ============================================
+// 55. Agent Transfer — propose + accept
+// ============================================
+
+#[test]
+fn test_agent_transfer_propose_accept() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TransferBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.propose_agent_transfer(&AGENT_OWNER, 1, &WORKER);
+    assert_eq!(
+        state.query_pending_agent_transfer(1).into_option(),
+        Some(WORKER.to_managed_address())
+    );
+
+    // Ownership only moves once the new owner accepts
+    assert_eq!(state.query_agent_owner(1), AGENT_OWNER.to_managed_address());
+    state.accept_agent_transfer(&WORKER, 1);
+    assert_eq!(state.query_agent_owner(1), WORKER.to_managed_address());
+    assert!(state.query_pending_agent_transfer(1).is_none());
+
+    // New owner can act on the agent, previous owner cannot
+    state.set_metadata(&WORKER, 1, vec![(b"team", b"new")]);
+    state.set_metadata_expect_err(
+        &AGENT_OWNER,
+        1,
+        vec![(b"team", b"old")],
+        "Only the agent owner can perform this action",
+    );
+
+    // Previous owner is free to register a fresh agent
+    state.register_agent(
+        &AGENT_OWNER,
+        b"SecondBot",
+        b"https://example.com/second",
+        b"pubkey456",
+        vec![],
+        vec![],
+    );
+    assert_eq!(state.query_agent_owner(2), AGENT_OWNER.to_managed_address());
+}
+
+// ============================================
+// 56. Agent Transfer — guards
+// ============================================
+
+#[test]
+fn test_agent_transfer_guards() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TransferBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.register_agent(
+        &CLIENT,
+        b"ClientBot",
+        b"https://example.com/client",
+        b"pubkey456",
+        vec![],
+        vec![],
+    );
+
+    // Only the owner can propose, and not to itself
+    state.propose_agent_transfer_expect_err(
+        &WORKER,
+        1,
+        &WORKER,
+        "Only the agent owner can perform this action",
+    );
+    state.propose_agent_transfer_expect_err(
+        &AGENT_OWNER,
+        1,
+        &AGENT_OWNER,
+        "Agent is already owned by this address",
+    );
+
+    // Nothing to accept yet
+    state.accept_agent_transfer_expect_err(&WORKER, 1, "No pending transfer for this agent");
+
+    // Only the proposed address can accept
+    state.propose_agent_transfer(&AGENT_OWNER, 1, &WORKER);
+    state.accept_agent_transfer_expect_err(
+        &VALIDATOR,
+        1,
+        "Only the proposed owner can accept the transfer",
+    );
+
+    // One agent per address: CLIENT already owns agent 2
+    state.propose_agent_transfer(&AGENT_OWNER, 1, &CLIENT);
+    state.accept_agent_transfer_expect_err(&CLIENT, 1, "Agent already registered for this address");
+    assert_eq!(state.query_agent_owner(1), AGENT_OWNER.to_managed_address());
+}
+
+// ============================================
+// 57. Agent Transfer — cancel
+// ============================================
+
+#[test]
+fn test_agent_transfer_cancel() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TransferBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.propose_agent_transfer(&AGENT_OWNER, 1, &WORKER);
+    state.cancel_agent_transfer(&AGENT_OWNER, 1);
+
+    state.accept_agent_transfer_expect_err(&WORKER, 1, "No pending transfer for this agent");
+    assert_eq!(state.query_agent_owner(1), AGENT_OWNER.to_managed_address());
+}
+
+// ============================================
+// 58. Multi-agent mode — one owner, many agents
+// ============================================
+
+#[test]
+fn test_multi_agent_mode_register_many() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"FleetA",
+        b"https://example.com/a",
+        b"pubkey-a",
+        vec![],
+        vec![],
+    );
+
+    // Default mode keeps one agent per address
+    state.register_agent_expect_err(
+        &AGENT_OWNER,
+        b"FleetB",
+        b"https://example.com/b",
+        b"pubkey-b",
+        "Agent already registered for this address",
+    );
+
+    state.set_multi_agent_mode(true);
+    for name in [b"FleetB", b"FleetC"] {
+        state.register_agent(
+            &AGENT_OWNER,
+            name,
+            b"https://example.com/fleet",
+            b"pubkey",
+            vec![],
+            vec![],
+        );
+    }
+
+    assert_eq!(state.query_agent_count_by_owner(&AGENT_OWNER), 3);
+    assert_eq!(state.query_agent_owner(3), AGENT_OWNER.to_managed_address());
+
+    // Pagination
+    let mut first_page = state.query_agents_by_owner(&AGENT_OWNER, 0, 2);
+    let second_page = state.query_agents_by_owner(&AGENT_OWNER, 2, 2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(second_page.len(), 1);
+    first_page.extend(second_page);
+    first_page.sort();
+    assert_eq!(first_page, vec![1u64, 2, 3]);
+    assert!(state.query_agents_by_owner(&AGENT_OWNER, 5, 2).is_empty());
+
+    // Each agent is managed independently by the same owner
+    state.set_metadata(&AGENT_OWNER, 2, vec![(b"role", b"indexer")]);
+    assert_eq!(
+        state.query_metadata(2, b"role").into_option().unwrap(),
+        ManagedBuffer::<StaticApi>::from(b"indexer")
+    );
+    assert!(state.query_metadata(1, b"role").is_none());
+}
+
+// ============================================
+// 59. Multi-agent mode — transfers and self-review
+// ============================================
+
+#[test]
+fn test_multi_agent_mode_transfer_and_self_review() {
+    let mut state = AgentTestState::new();
+    state.set_multi_agent_mode(true);
+    state.register_agent(
+        &AGENT_OWNER,
+        b"FleetA",
+        b"https://example.com/a",
+        b"pubkey-a",
+        vec![],
+        vec![],
+    );
+    state.register_agent(
+        &CLIENT,
+        b"ClientBot",
+        b"https://example.com/c",
+        b"pubkey-c",
+        vec![],
+        vec![],
+    );
+
+    // An existing owner can accept more agents
+    state.propose_agent_transfer(&CLIENT, 2, &AGENT_OWNER);
+    state.accept_agent_transfer(&AGENT_OWNER, 2);
+    assert_eq!(state.query_agent_owner(2), AGENT_OWNER.to_managed_address());
+    assert_eq!(state.query_agent_count_by_owner(&AGENT_OWNER), 2);
+    assert_eq!(state.query_agent_count_by_owner(&CLIENT), 0);
+
+    // Self-review is rejected for every agent the caller owns
+    for nonce in [1u64, 2] {
+        state.give_feedback_expect_err(
+            &AGENT_OWNER,
+            nonce,
+            80,
+            "Agent owner cannot give feedback to own agent",
+        );
+    }
+    state.give_feedback(&CLIENT, 2, 80);
+}
+
+// ============================================
+// 60. Upgrade migrates legacy 1:1 owner mapping, in batches
+// ============================================
+
+#[test]
+fn test_upgrade_migrates_legacy_agents() {
+    let mut state = AgentTestState::new();
+    state.seed_legacy_agent(7, &AGENT_OWNER);
+    state.seed_legacy_agent(8, &CLIENT);
+
+    // Migrated in batches: the first upgrade only moves agent 7
+    state.upgrade_identity_migrating(&[7]);
+    assert_eq!(state.query_agents_by_owner(&AGENT_OWNER, 0, 10), vec![7u64]);
+    assert!(state.query_agents_by_owner(&CLIENT, 0, 10).is_empty());
+
+    // Already migrated and unknown nonces are skipped
+    state.upgrade_identity_migrating(&[7, 8, 9]);
+
+    assert_eq!(state.query_agent_owner(7), AGENT_OWNER.to_managed_address());
+    assert_eq!(state.query_agent_owner(8), CLIENT.to_managed_address());
+    assert_eq!(state.query_agents_by_owner(&AGENT_OWNER, 0, 10), vec![7u64]);
+    assert_eq!(state.query_agents_by_owner(&CLIENT, 0, 10), vec![8u64]);
+}
+
+// ============================================
+// 61. Deregister agent — burn and cleanup
+// ============================================
+
+#[test]
+fn test_deregister_agent() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"RetiringBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![(b"team", b"ops")],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.propose_agent_transfer(&AGENT_OWNER, 1, &WORKER);
+
+    state.deregister_agent(&AGENT_OWNER, 1);
+
+    state.check_agent_retired(1);
+    assert_eq!(state.query_agent_count_by_owner(&AGENT_OWNER), 0);
+    state.accept_agent_transfer_expect_err(&WORKER, 1, "No pending transfer for this agent");
+    state.set_metadata_expect_err(&AGENT_OWNER, 1, vec![(b"team", b"x")], "Agent not found");
+    state.deregister_agent_expect_err(&AGENT_OWNER, 1, "Agent not found");
+
+    // The owner is free to register again; the retired nonce is not reused
+    state.register_agent(
+        &AGENT_OWNER,
+        b"NextBot",
+        b"https://example.com/next",
+        b"pubkey456",
+        vec![],
+        vec![],
+    );
+    assert_eq!(state.query_agents_by_owner(&AGENT_OWNER, 0, 10), vec![2u64]);
+}
+
+// ============================================
+// 62. Deregister agent — guards and downstream rejection
+// ============================================
+
+#[test]
+fn test_deregister_agent_rejects_new_work() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"RetiringBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job-before", 1, None);
+
+    state.deregister_agent_expect_err(&WORKER, 1, "Only the agent owner can perform this action");
+    state.deregister_agent(&AGENT_OWNER, 1);
+
+    state.init_job_expect_err(&CLIENT, b"job-after", 1, None, "Agent not found");
+    state.give_feedback_expect_err(&CLIENT, 1, 80, "Agent not found");
+    state.give_feedback_expect_err(&CLIENT, 99, 80, "Agent not found");
+}
+
+// ============================================
+// 63. Pause / resume agent
+// ============================================
+
+#[test]
+fn test_pause_resume_agent() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"MaintBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![(b"team", b"ops")],
+        vec![],
+    );
+    assert_eq!(state.query_agent_status(1), AgentStatus::Active);
+
+    state.pause_agent_expect_err(&WORKER, 1, "Only the agent owner can perform this action");
+    state.resume_agent_expect_err(&AGENT_OWNER, 1, "Agent is not paused");
+
+    state.pause_agent(&AGENT_OWNER, 1);
+    assert_eq!(state.query_agent_status(1), AgentStatus::Paused);
+    state.pause_agent_expect_err(&AGENT_OWNER, 1, "Agent is paused");
+
+    // Identity and metadata survive; new jobs are refused
+    assert_eq!(state.query_agent_owner(1), AGENT_OWNER.to_managed_address());
+    assert!(state.query_metadata(1, b"team").is_some());
+    state.init_job_expect_err(&CLIENT, b"job-paused", 1, None, "Agent is paused");
+
+    state.resume_agent(&AGENT_OWNER, 1);
+    assert_eq!(state.query_agent_status(1), AgentStatus::Active);
+    state.init_job(&CLIENT, b"job-paused", 1, None);
+}
+
+// ============================================
+// 64. Operators — scoped delegation
+// ============================================
+
+#[test]
+fn test_agent_operator_permissions() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"DelegatedBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.set_agent_operator(
+        &AGENT_OWNER,
+        1,
+        &WORKER,
+        PERMISSION_EDIT_METADATA | PERMISSION_REQUEST_VALIDATION,
+    );
+    assert_eq!(
+        state.query_operator_permissions(1, &WORKER),
+        PERMISSION_EDIT_METADATA | PERMISSION_REQUEST_VALIDATION
+    );
+
+    // Granted scopes
+    state.set_metadata(&WORKER, 1, vec![(b"runtime", b"v2")]);
+    state.init_job(&CLIENT, b"job_op", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_op", b"proof");
+    state.validation_request(
+        &WORKER,
+        b"job_op",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"op_hash",
+    );
+
+    // Scope not granted
+    state.set_service_configs_expect_err(
+        &WORKER,
+        1,
+        vec![(1u32, 100u64, b"EGLD-000000", 0u64)],
+        "Only the agent owner can perform this action",
+    );
+    state.set_agent_operator(&AGENT_OWNER, 1, &WORKER, PERMISSION_EDIT_SERVICES);
+    state.set_service_configs(&WORKER, 1, vec![(1u32, 100u64, b"EGLD-000000", 0u64)]);
+    state.set_metadata_expect_err(
+        &WORKER,
+        1,
+        vec![(b"runtime", b"v3")],
+        "Only the agent owner can perform this action",
+    );
+
+    // Revoked operator loses every scope
+    state.remove_agent_operator(&AGENT_OWNER, 1, &WORKER);
+    assert_eq!(state.query_operator_permissions(1, &WORKER), 0);
+    state.set_service_configs_expect_err(
+        &WORKER,
+        1,
+        vec![(2u32, 100u64, b"EGLD-000000", 0u64)],
+        "Only the agent owner can perform this action",
+    );
+}
+
+// ============================================
+// 65. Operators — guards and transfer revocation
+// ============================================
+
+#[test]
+fn test_agent_operator_guards() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"DelegatedBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.set_agent_operator_expect_err(
+        &WORKER,
+        1,
+        &WORKER,
+        PERMISSION_EDIT_METADATA,
+        "Only the agent owner can perform this action",
+    );
+    state.set_agent_operator_expect_err(
+        &AGENT_OWNER,
+        1,
+        &AGENT_OWNER,
+        PERMISSION_EDIT_METADATA,
+        "Agent owner cannot be its own operator",
+    );
+    state.set_agent_operator_expect_err(
+        &AGENT_OWNER,
+        1,
+        &WORKER,
+        0,
+        "Invalid operator permissions",
+    );
+    state.set_agent_operator_expect_err(
+        &AGENT_OWNER,
+        1,
+        &WORKER,
+        0x10,
+        "Invalid operator permissions",
+    );
+    state.remove_agent_operator_expect_err(&AGENT_OWNER, 1, &WORKER, "Operator not found");
+
+    // Operators cannot manage operators or ownership
+    state.set_agent_operator(&AGENT_OWNER, 1, &WORKER, PERMISSION_EDIT_METADATA);
+    state.set_agent_operator_expect_err(
+        &WORKER,
+        1,
+        &CLIENT,
+        PERMISSION_EDIT_METADATA,
+        "Only the agent owner can perform this action",
+    );
+    state.propose_agent_transfer_expect_err(
+        &WORKER,
+        1,
+        &CLIENT,
+        "Only the agent owner can perform this action",
+    );
+
+    // Ownership transfer revokes existing operators
+    state.propose_agent_transfer(&AGENT_OWNER, 1, &CLIENT);
+    state.accept_agent_transfer(&CLIENT, 1);
+    assert_eq!(state.query_operator_permissions(1, &WORKER), 0);
+    state.set_metadata_expect_err(
+        &WORKER,
+        1,
+        vec![(b"runtime", b"v2")],
+        "Only the agent owner can perform this action",
+    );
+}
+
+// ============================================
+// 66. Signed proof — relayed submission
+// ============================================
+
+const AGENT_KEY_SEED: [u8; 32] = [7u8; 32];
+const OTHER_KEY_SEED: [u8; 32] = [9u8; 32];
+
+#[test]
+fn test_submit_signed_proof() {
+    let mut state = AgentTestState::new();
+    let public_key = agent_public_key(&AGENT_KEY_SEED);
+    state.register_agent(
+        &AGENT_OWNER,
+        b"WalletlessBot",
+        b"https://example.com/manifest",
+        &public_key,
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_signed", 1, None);
+
+    // Any relayer can submit a proof signed by the agent key
+    let signature = sign_proof(&AGENT_KEY_SEED, b"job_signed", 0, b"proof-hash");
+    state.submit_signed_proof(&WORKER, b"job_signed", b"proof-hash", &signature);
+
+    let job = state.query_job_data(b"job_signed").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.proof, ManagedBuffer::<StaticApi>::from(b"proof-hash"));
+}
+
+// ============================================
+// 67. Signed proof — invalid signatures
+// ============================================
+
+#[test]
+fn test_submit_signed_proof_invalid() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"WalletlessBot",
+        b"https://example.com/manifest",
+        &agent_public_key(&AGENT_KEY_SEED),
+        vec![],
+        vec![],
+    );
+    state.register_agent(
+        &CLIENT,
+        b"LegacyKeyBot",
+        b"https://example.com/legacy",
+        b"not-a-key",
+        vec![],
+        vec![],
+    );
+    state.init_job(&VALIDATOR, b"job_signed", 1, None);
+    state.init_job(&VALIDATOR, b"job_legacy", 2, None);
+
+    // Wrong signer
+    let signature = sign_proof(&OTHER_KEY_SEED, b"job_signed", 0, b"proof-hash");
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_signed",
+        b"proof-hash",
+        &signature,
+        "ed25519 verify error",
+    );
+
+    // Signature does not cover a tampered proof or another job
+    let signature = sign_proof(&AGENT_KEY_SEED, b"job_signed", 0, b"proof-hash");
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_signed",
+        b"tampered",
+        &signature,
+        "ed25519 verify error",
+    );
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_signe",
+        b"dproof-hash",
+        &signature,
+        "Job not found",
+    );
+
+    // Agent registered with a non-Ed25519 key
+    let signature = sign_proof(&AGENT_KEY_SEED, b"job_legacy", 0, b"proof-hash");
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_legacy",
+        b"proof-hash",
+        &signature,
+        "Agent public key is not a valid Ed25519 key",
+    );
+
+    let job = state.query_job_data(b"job_signed").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::New);
+}
+
+// ============================================
+// 68. Signed proof — key rotation via update_agent
+// ============================================
+
+#[test]
+fn test_submit_signed_proof_after_key_rotation() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"RotatingBot",
+        b"https://example.com/manifest",
+        &agent_public_key(&AGENT_KEY_SEED),
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_rotate", 1, None);
+
+    let new_public_key = agent_public_key(&OTHER_KEY_SEED);
+    state.update_agent_raw(
+        &AGENT_OWNER,
+        1,
+        b"RotatingBot",
+        b"https://example.com/manifest",
+        &new_public_key,
+        None,
+        None,
+    );
+    assert_eq!(
+        state.query_agent_details(1).public_key,
+        ManagedBuffer::<StaticApi>::from(new_public_key.as_slice())
+    );
+
+    let old_signature = sign_proof(&AGENT_KEY_SEED, b"job_rotate", 0, b"proof-hash");
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_rotate",
+        b"proof-hash",
+        &old_signature,
+        "ed25519 verify error",
+    );
+
+    let new_signature = sign_proof(&OTHER_KEY_SEED, b"job_rotate", 0, b"proof-hash");
+    state.submit_signed_proof(&WORKER, b"job_rotate", b"proof-hash", &new_signature);
+    assert_eq!(
+        state
+            .query_job_data(b"job_rotate")
+            .into_option()
+            .unwrap()
+            .status,
+        JobStatus::Pending
+    );
+}
+
+// ============================================
+// 69. Submit Proof — caller authorisation
+// ============================================
+
+#[test]
+fn test_submit_proof_unauthorised() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_auth", 1, None);
+
+    // Neither the employer nor an arbitrary address can submit or overwrite a proof
+    state.submit_proof_expect_err(
+        &CLIENT,
+        b"job_auth",
+        b"griefed",
+        "Only the agent owner can perform this action",
+    );
+    state.submit_proof_expect_err(
+        &WORKER,
+        b"job_auth",
+        b"griefed",
+        "Only the agent owner can perform this action",
+    );
+
+    // An operator needs the submit-proof scope specifically
+    state.set_agent_operator(&AGENT_OWNER, 1, &WORKER, PERMISSION_EDIT_METADATA);
+    state.submit_proof_expect_err(
+        &WORKER,
+        b"job_auth",
+        b"proof",
+        "Only the agent owner can perform this action",
+    );
+    state.set_agent_operator(&AGENT_OWNER, 1, &WORKER, PERMISSION_SUBMIT_PROOF);
+    state.submit_proof(&WORKER, b"job_auth", b"proof-from-operator");
+
+    let job = state.query_job_data(b"job_auth").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(
+        job.proof,
+        ManagedBuffer::<StaticApi>::from(b"proof-from-operator")
+    );
+}
+
+// ============================================
+// 70. Submit Proof — frozen once validation is requested
+// ============================================
+
+#[test]
+fn test_submit_proof_locked_after_validation_request() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        &agent_public_key(&AGENT_KEY_SEED),
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_lock", 1, None);
+
+    // Re-submission is fine while the job is still Pending
+    state.submit_proof(&AGENT_OWNER, b"job_lock", b"draft");
+    state.submit_proof(&AGENT_OWNER, b"job_lock", b"final");
+
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_lock",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"lock_hash",
+    );
+    state.submit_proof_expect_err(
+        &AGENT_OWNER,
+        b"job_lock",
+        b"swapped",
+        "Proof cannot be changed once validation is requested",
+    );
+    let signature = sign_proof(&AGENT_KEY_SEED, b"job_lock", 2, b"swapped");
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_lock",
+        b"swapped",
+        &signature,
+        "Proof cannot be changed once validation is requested",
+    );
+
+    state.validation_response(
+        &VALIDATOR,
+        b"lock_hash",
+        90,
+        b"https://resp.uri",
+        b"lock_resp",
+        b"approved",
+    );
+    state.submit_proof_expect_err(
+        &AGENT_OWNER,
+        b"job_lock",
+        b"swapped",
+        "Proof cannot be changed once validation is requested",
+    );
+
+    let job = state.query_job_data(b"job_lock").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Verified);
+    assert_eq!(job.proof, ManagedBuffer::<StaticApi>::from(b"final"));
+}
+
+// ============================================
+// 71. Job state machine — illegal transitions
+// ============================================
+
+#[test]
+fn test_job_state_machine_transitions() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_sm", 1, None);
+
+    // New -> ValidationRequested is not allowed without a proof
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_sm",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"sm_hash",
+        "Proof must be submitted before requesting validation",
+    );
+
+    state.submit_proof(&AGENT_OWNER, b"job_sm", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_sm",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"sm_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"sm_hash",
+        90,
+        b"https://resp.uri",
+        b"sm_resp",
+        b"approved",
+    );
+
+    // Verified is final: no new validation rounds, no proof changes
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_sm",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"sm_hash_2",
+        "Job is already finalized",
+    );
+    state.submit_proof_expect_err(
+        &AGENT_OWNER,
+        b"job_sm",
+        b"proof-2",
+        "Proof cannot be changed once validation is requested",
+    );
+    assert_eq!(
+        state
+            .query_job_data(b"job_sm")
+            .into_option()
+            .unwrap()
+            .status,
+        JobStatus::Verified
+    );
+}
+
+// ============================================
+// 72. Job state machine — jobStatusChanged event
+// ============================================
+
+#[test]
+fn test_job_status_changed_event() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_ev", 1, None);
+
+    let logs = state.submit_proof_with_logs(&AGENT_OWNER, b"job_ev", b"proof");
+    let expected_topics = vec![
+        b"jobStatusChanged".to_vec(),
+        b"job_ev".to_vec(),
+        top_encode_to_vec_u8_or_panic(&JobStatus::New),
+        top_encode_to_vec_u8_or_panic(&JobStatus::Pending),
+    ];
+    assert!(logs.iter().any(|log| log.topics == expected_topics));
+
+    // Re-submitting a proof keeps the job Pending and emits no status change
+    let logs = state.submit_proof_with_logs(&AGENT_OWNER, b"job_ev", b"proof-2");
+    assert!(
+        !logs
+            .iter()
+            .any(|log| log.topics.first() == Some(&b"jobStatusChanged".to_vec()))
+    );
+}
+
+// ============================================
+// 73. Pass threshold — low responses reject the job
+// ============================================
+
+#[test]
+fn test_validation_response_below_threshold_rejects() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    assert_eq!(state.query_pass_threshold(), 50);
+
+    state.init_job(&CLIENT, b"job_low", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_low", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_low",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"low_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"low_hash",
+        49,
+        b"https://resp.uri",
+        b"low_resp",
+        b"failed",
+    );
+    assert!(!state.query_is_job_verified(b"job_low"));
+
+    // A later (progressive) response updates the record but not the outcome
+    state.validation_response(
+        &VALIDATOR,
+        b"low_hash",
+        100,
+        b"https://resp.uri",
+        b"low_resp_2",
+        b"approved",
+    );
+    assert_eq!(
+        state
+            .query_job_data(b"job_low")
+            .into_option()
+            .unwrap()
+            .status,
+        JobStatus::Rejected
+    );
+
+    // Exactly at the threshold passes
+    state.init_job(&CLIENT, b"job_edge", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_edge", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_edge",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"edge_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"edge_hash",
+        50,
+        b"https://resp.uri",
+        b"edge_resp",
+        b"approved",
+    );
+    assert!(state.query_is_job_verified(b"job_edge"));
+
+    // Only the owner can move the global threshold, and only within 0-100
+    state.set_pass_threshold_expect_err(&AGENT_OWNER, 80, "Endpoint can only be called by owner");
+    state.set_pass_threshold_expect_err(
+        &OWNER_ADDRESS,
+        101,
+        "Pass threshold must not exceed the maximum validation score",
+    );
+    state.set_pass_threshold(&OWNER_ADDRESS, 80);
+    state.upgrade_validation();
+    assert_eq!(state.query_pass_threshold(), 80);
+}
+
+// ============================================
+// 74. Pass threshold — per-service override
+// ============================================
+
+#[test]
+fn test_service_pass_threshold() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.set_service_pass_threshold_expect_err(
+        &AGENT_OWNER,
+        1,
+        2,
+        90,
+        "Service config not found for agent",
+    );
+    state.set_service_pass_threshold_expect_err(
+        &AGENT_OWNER,
+        1,
+        1,
+        101,
+        "Pass threshold must not exceed the maximum validation score",
+    );
+    state.set_service_pass_threshold_expect_err(
+        &CLIENT,
+        1,
+        1,
+        90,
+        "Only the agent owner can perform this action",
+    );
+    state.set_service_pass_threshold(&AGENT_OWNER, 1, 1, 90);
+    assert_eq!(
+        state.query_service_pass_threshold(1, 1).into_option(),
+        Some(90)
+    );
+
+    // A response that clears the global threshold still fails the service threshold
+    state.init_job_with_payment(&CLIENT, b"job_svc", 1, 1, "USDC-abcdef", 0, 100);
+    state.submit_proof(&AGENT_OWNER, b"job_svc", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_svc",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"svc_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"svc_hash",
+        80,
+        b"https://resp.uri",
+        b"svc_resp",
+        b"approved",
+    );
+    assert!(!state.query_is_job_verified(b"job_svc"));
+
+    // A service threshold below the global one cannot lower the bar
+    state.set_service_pass_threshold(&AGENT_OWNER, 1, 1, 10);
+    state.init_job_with_payment(&CLIENT, b"job_svc_2", 1, 1, "USDC-abcdef", 0, 100);
+    state.submit_proof(&AGENT_OWNER, b"job_svc_2", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_svc_2",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"svc_hash_2",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"svc_hash_2",
+        20,
+        b"https://resp.uri",
+        b"svc_resp_2",
+        b"approved",
+    );
+    assert!(!state.query_is_job_verified(b"job_svc_2"));
+
+    // Removing the service drops its threshold
+    state.remove_service_configs(&AGENT_OWNER, 1, vec![1]);
+    assert!(
+        state
+            .query_service_pass_threshold(1, 1)
+            .into_option()
+            .is_none()
+    );
+}
+
+// ============================================
+// 75. Quorum validation — 2-of-3 with median aggregate
+// ============================================
+
+#[test]
+fn test_quorum_validation() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_q", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_q", b"proof");
+    state.quorum_validation_request(
+        &AGENT_OWNER,
+        b"job_q",
+        vec![&VALIDATOR, &VALIDATOR_2, &VALIDATOR_3],
+        2,
+        b"q_hash",
+    );
+
+    // One response is not enough to decide the job
+    state.validation_response(
+        &VALIDATOR,
+        b"q_hash",
+        40,
+        b"https://resp.uri",
+        b"q_resp_1",
+        b"partial",
+    );
+    assert_eq!(
+        state.query_job_data(b"job_q").into_option().unwrap().status,
+        JobStatus::ValidationRequested
+    );
+
+    // Second response reaches the quorum: median of 40 and 80 is 60 -> Verified
+    state.validation_response(
+        &VALIDATOR_2,
+        b"q_hash",
+        80,
+        b"https://resp.uri",
+        b"q_resp_2",
+        b"approved",
+    );
+    assert!(state.query_is_job_verified(b"job_q"));
+
+    let outcome = state.query_validation_outcome(b"q_hash");
+    assert_eq!(outcome.quorum, 2);
+    assert_eq!(outcome.validator_count, 3);
+    assert_eq!(outcome.response_count, 2);
+    assert_eq!(outcome.aggregate_response, 60);
+    assert!(outcome.quorum_reached);
+
+    let response = state
+        .query_validator_response(b"q_hash", &VALIDATOR)
+        .into_option()
+        .unwrap();
+    assert_eq!(response.response, 40);
+    assert_eq!(response.response_hash, ManagedBuffer::from(b"q_resp_1"));
+    assert!(
+        state
+            .query_validator_response(b"q_hash", &VALIDATOR_3)
+            .into_option()
+            .is_none()
+    );
+
+    // Late responses refine the aggregate without changing the outcome
+    state.validation_response(
+        &VALIDATOR_3,
+        b"q_hash",
+        0,
+        b"https://resp.uri",
+        b"q_resp_3",
+        b"failed",
+    );
+    assert_eq!(
+        state.query_validation_outcome(b"q_hash").aggregate_response,
+        40
+    );
+    assert!(state.query_is_job_verified(b"job_q"));
+}
+
+// ============================================
+// 76. Quorum validation — errors
+// ============================================
+
+#[test]
+fn test_quorum_validation_errors() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_qe", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_qe", b"proof");
+
+    state.quorum_validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_qe",
+        vec![&VALIDATOR, &VALIDATOR_2],
+        0,
+        b"qe_hash",
+        "Quorum must be between 1 and the number of validators",
+    );
+    state.quorum_validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_qe",
+        vec![&VALIDATOR, &VALIDATOR_2],
+        3,
+        b"qe_hash",
+        "Quorum must be between 1 and the number of validators",
+    );
+    state.quorum_validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_qe",
+        vec![&VALIDATOR, &VALIDATOR],
+        1,
+        b"qe_hash",
+        "Validator nominated more than once",
+    );
+    state.quorum_validation_request_expect_err(
+        &CLIENT,
+        b"job_qe",
+        vec![&VALIDATOR, &VALIDATOR_2],
+        1,
+        b"qe_hash",
+        "Only the agent owner can perform this action",
+    );
+
+    state.quorum_validation_request(
+        &AGENT_OWNER,
+        b"job_qe",
+        vec![&VALIDATOR, &VALIDATOR_2],
+        2,
+        b"qe_hash",
+    );
+    state.validation_response_expect_err(
+        &VALIDATOR_3,
+        b"qe_hash",
+        90,
+        b"https://resp.uri",
+        b"qe_resp",
+        b"approved",
+        "Only the designated validator can respond",
+    );
+    state.validation_response_expect_err(
+        &VALIDATOR,
+        b"qe_hash",
+        101,
+        b"https://resp.uri",
+        b"qe_resp",
+        b"approved",
+        "Response must not exceed the maximum validation score",
+    );
+}
+
+// ============================================
+// 77. Validator registry — registration and tags
+// ============================================
+
+#[test]
+fn test_validator_registration_and_tags() {
+    let mut state = AgentTestState::new();
+
+    state.register_validator_expect_err(&VALIDATOR, 1_000, "Validator staking is not configured");
+
+    state.set_validator_staking(1_000, 3_600);
+    state.register_validator_expect_err(&VALIDATOR, 999, "Stake below the minimum");
+    state.register_validator(&VALIDATOR, 1_000, vec![b"code-review", b"translation"]);
+    state.register_validator_expect_err(&VALIDATOR, 1_000, "Validator already registered");
+
+    let info = state.query_validator(&VALIDATOR).into_option().unwrap();
+    assert_eq!(info.stake, BigUint::<StaticApi>::from(1_000u64));
+    assert_eq!(info.status, ValidatorStatus::Active);
+    assert_eq!(
+        state.query_validators_by_tag(b"code-review"),
+        vec![VALIDATOR.to_managed_address()]
+    );
+
+    // Replacing tags re-indexes the validator
+    state.set_validator_tags(&VALIDATOR, vec![b"translation", b"audit"]);
+    assert!(state.query_validators_by_tag(b"code-review").is_empty());
+    assert_eq!(
+        state.query_validators_by_tag(b"audit"),
+        vec![VALIDATOR.to_managed_address()]
+    );
+}
+
+// ============================================
+// 78. Validator registry — staked validators required
+// ============================================
+
+#[test]
+fn test_staked_validator_required() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_validator_staking(1_000, 3_600);
+    state.register_validator(&VALIDATOR_2, 1_000, vec![]);
+    state.set_require_staked_validators(true);
+
+    state.init_job(&CLIENT, b"job_staked", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_staked", b"proof");
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_staked",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"staked_hash",
+        "Validator is not active or not sufficiently staked",
+    );
+    state.quorum_validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_staked",
+        vec![&VALIDATOR_2, &VALIDATOR],
+        1,
+        b"staked_hash",
+        "Validator is not active or not sufficiently staked",
+    );
+
+    // Unbonding validators can no longer be nominated
+    state.unbond_validator(&VALIDATOR_2);
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_staked",
+        &VALIDATOR_2,
+        b"https://val.uri",
+        b"staked_hash",
+        "Validator is not active or not sufficiently staked",
+    );
+
+    state.register_validator(&VALIDATOR_3, 1_500, vec![]);
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_staked",
+        &VALIDATOR_3,
+        b"https://val.uri",
+        b"staked_hash",
+    );
+}
+
+// ============================================
+// 79. Validator registry — slashing and withdrawal
+// ============================================
+
+#[test]
+fn test_validator_slashing_and_withdrawal() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_validator_staking(1_000, 3_600);
+    state.register_validator(&VALIDATOR, 1_000, vec![]);
+
+    state.init_job(&CLIENT, b"job_slash", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_slash", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_slash",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"slash_hash",
+    );
+
+    // Nothing to slash before the validator has responded
+    state.slash_validator_expect_err(
+        &OWNER_ADDRESS,
+        &VALIDATOR,
+        b"slash_hash",
+        400,
+        "Validator did not respond to this request",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"slash_hash",
+        100,
+        b"https://resp.uri",
+        b"slash_resp",
+        b"approved",
+    );
+    state.slash_validator_expect_err(
+        &CLIENT,
+        &VALIDATOR,
+        b"slash_hash",
+        400,
+        "Endpoint can only be called by owner",
+    );
+    state.slash_validator_expect_err(
+        &OWNER_ADDRESS,
+        &VALIDATOR,
+        b"slash_hash",
+        1_001,
+        "Slash amount must be positive and within the stake",
+    );
+    state.slash_validator(&VALIDATOR, b"slash_hash", 400);
+
+    let info = state.query_validator(&VALIDATOR).into_option().unwrap();
+    assert_eq!(info.stake, BigUint::<StaticApi>::from(600u64));
+
+    // Unbond, wait out the period, withdraw what is left
+    state.world.current_block().block_timestamp_seconds(1_000);
+    state.withdraw_validator_stake_expect_err(&VALIDATOR, "Validator is not unbonding");
+    state.unbond_validator(&VALIDATOR);
+    state.world.current_block().block_timestamp_seconds(4_599);
+    state.withdraw_validator_stake_expect_err(&VALIDATOR, "Unbonding period has not passed yet");
+    state.world.current_block().block_timestamp_seconds(4_600);
+    state.withdraw_validator_stake(&VALIDATOR);
+
+    assert!(state.query_validator(&VALIDATOR).into_option().is_none());
+    state
+        .world
+        .check_account(VALIDATOR)
+        .balance(1_000_000u64 - 400);
+}
+
+// ============================================
+// 80. Validator fee — paid on response
+// ============================================
+
+#[test]
+fn test_validator_fee_paid_on_response() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_fee", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_fee", b"proof");
+    state.validation_request_with_fee(&AGENT_OWNER, b"job_fee", &VALIDATOR, b"fee_hash", 1_000);
+
+    let fee = state
+        .query_validation_fee(b"fee_hash")
+        .into_option()
+        .unwrap();
+    assert_eq!(fee.remaining, BigUint::<StaticApi>::from(1_000u64));
+    assert_eq!(fee.payer, AGENT_OWNER.to_managed_address());
+
+    state.validation_response(
+        &VALIDATOR,
+        b"fee_hash",
+        90,
+        b"https://resp.uri",
+        b"fee_resp",
+        b"approved",
+    );
+    // Progressive responses are not paid twice
+    state.validation_response(
+        &VALIDATOR,
+        b"fee_hash",
+        95,
+        b"https://resp.uri",
+        b"fee_resp_2",
+        b"approved",
+    );
+
+    assert!(
+        state
+            .query_validation_fee(b"fee_hash")
+            .into_option()
+            .is_none()
+    );
+    state
+        .world
+        .check_account(VALIDATOR)
+        .balance(1_000_000u64 + 1_000);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 - 1_000);
+    state.refund_validation_fee_expect_err(
+        &AGENT_OWNER,
+        b"fee_hash",
+        "No validation fee escrowed for this request",
+    );
+}
+
+// ============================================
+// 81. Validator fee — unanswered share refunded after timeout
+// ============================================
+
+#[test]
+fn test_validator_fee_refund_after_timeout() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_validator_fee_timeout(100);
+    state.world.current_block().block_timestamp_seconds(1_000);
+
+    state.init_job(&CLIENT, b"job_fee_q", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_fee_q", b"proof");
+    state.quorum_validation_request_with_fee(
+        &AGENT_OWNER,
+        b"job_fee_q",
+        vec![&VALIDATOR, &VALIDATOR_2, &VALIDATOR_3],
+        2,
+        b"fee_q_hash",
+        1_000,
+    );
+
+    // Each validator's share is 1000 / 3 = 333
+    state.validation_response(
+        &VALIDATOR,
+        b"fee_q_hash",
+        90,
+        b"https://resp.uri",
+        b"fee_q_resp_1",
+        b"approved",
+    );
+    state
+        .world
+        .check_account(VALIDATOR)
+        .balance(1_000_000u64 + 333);
+
+    state.refund_validation_fee_expect_err(
+        &CLIENT,
+        b"fee_q_hash",
+        "Validation fee timeout has not passed yet",
+    );
+
+    // Late responses still count toward the quorum but are no longer paid
+    state.world.current_block().block_timestamp_seconds(1_101);
+    state.validation_response(
+        &VALIDATOR_2,
+        b"fee_q_hash",
+        90,
+        b"https://resp.uri",
+        b"fee_q_resp_2",
+        b"approved",
+    );
+    assert!(state.query_is_job_verified(b"job_fee_q"));
+    state.world.check_account(VALIDATOR_2).balance(1_000_000u64);
+
+    // Anyone can trigger the refund; funds go back to the payer
+    state.refund_validation_fee(&CLIENT, b"fee_q_hash");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 - 333);
+    assert!(
+        state
+            .query_validation_fee(b"fee_q_hash")
+            .into_option()
+            .is_none()
+    );
+}
+
+// ============================================
+// 82. Validation request timeout — expiry rolls the job back
+// ============================================
+
+#[test]
+fn test_expire_validation_request() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_validation_request_timeout(100);
+    state.set_expiry_grace_period(50);
+    state.world.current_block().block_timestamp_seconds(1_000);
+
+    state.init_job(&CLIENT, b"job_exp", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_exp", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_exp",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"exp_hash_1",
+    );
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_exp",
+        &VALIDATOR_2,
+        b"https://val.uri",
+        b"exp_hash_2",
+    );
+    assert_eq!(
+        state
+            .query_validation_outcome(b"exp_hash_1")
+            .deadline
+            .as_u64_seconds(),
+        1_100
+    );
+
+    state.expire_validation_request_expect_err(
+        &CLIENT,
+        b"exp_hash_1",
+        "Validation request deadline has not passed yet",
+    );
+
+    // Past the deadline, the grace period is reserved for renominating
+    state.world.current_block().block_timestamp_seconds(1_150);
+    state.expire_validation_request_expect_err(
+        &CLIENT,
+        b"exp_hash_1",
+        "Validation request is still in its renomination grace period",
+    );
+
+    // Expiring one of two open requests keeps the job waiting on the other
+    state.world.current_block().block_timestamp_seconds(1_151);
+    state.expire_validation_request(&CLIENT, b"exp_hash_1");
+    assert!(state.query_validation_outcome(b"exp_hash_1").expired);
+    assert_eq!(
+        state
+            .query_job_data(b"job_exp")
+            .into_option()
+            .unwrap()
+            .status,
+        JobStatus::ValidationRequested
+    );
+    state.expire_validation_request_expect_err(
+        &CLIENT,
+        b"exp_hash_1",
+        "Validation request has expired",
+    );
+    state.validation_response_expect_err(
+        &VALIDATOR,
+        b"exp_hash_1",
+        90,
+        b"https://resp.uri",
+        b"exp_resp",
+        b"approved",
+        "Validation request has expired",
+    );
+
+    // Expiring the last one rolls the job back to Pending
+    state.expire_validation_request(&CLIENT, b"exp_hash_2");
+    assert_eq!(
+        state
+            .query_job_data(b"job_exp")
+            .into_option()
+            .unwrap()
+            .status,
+        JobStatus::Pending
+    );
+
+    // The agent may now replace the proof and ask again
+    state.submit_proof(&AGENT_OWNER, b"job_exp", b"proof-2");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_exp",
+        &VALIDATOR_3,
+        b"https://val.uri",
+        b"exp_hash_3",
+    );
+    state.validation_response(
+        &VALIDATOR_3,
+        b"exp_hash_3",
+        90,
+        b"https://resp.uri",
+        b"exp_resp_3",
+        b"approved",
+    );
+    assert!(state.query_is_job_verified(b"job_exp"));
+}
+
+// ============================================
+// 83. Validation request timeout — re-nominating a silent validator
+// ============================================
+
+#[test]
+fn test_renominate_validator() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_validation_request_timeout(100);
+    state.world.current_block().block_timestamp_seconds(1_000);
+
+    state.init_job(&CLIENT, b"job_ren", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_ren", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_ren",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"ren_hash",
+    );
+
+    state.renominate_validator_expect_err(
+        &AGENT_OWNER,
+        b"ren_hash",
+        &VALIDATOR,
+        &VALIDATOR_2,
+        "Validation request deadline has not passed yet",
+    );
+
+    state.world.current_block().block_timestamp_seconds(1_101);
+    // Nobody can expire the request ahead of the agent's renomination
+    state.expire_validation_request_expect_err(
+        &CLIENT,
+        b"ren_hash",
+        "Validation request is still in its renomination grace period",
+    );
+    state.renominate_validator_expect_err(
+        &CLIENT,
+        b"ren_hash",
+        &VALIDATOR,
+        &VALIDATOR_2,
+        "Only the agent owner can perform this action",
+    );
+    state.renominate_validator_expect_err(
+        &AGENT_OWNER,
+        b"ren_hash",
+        &VALIDATOR_3,
+        &VALIDATOR_2,
+        "Only the designated validator can respond",
+    );
+    state.renominate_validator(&AGENT_OWNER, b"ren_hash", &VALIDATOR, &VALIDATOR_2);
+    assert_eq!(
+        state
+            .query_validation_outcome(b"ren_hash")
+            .deadline
+            .as_u64_seconds(),
+        1_201
+    );
+
+    state.validation_response_expect_err(
+        &VALIDATOR,
+        b"ren_hash",
+        90,
+        b"https://resp.uri",
+        b"ren_resp",
+        b"approved",
+        "Only the designated validator can respond",
+    );
+    state.validation_response(
+        &VALIDATOR_2,
+        b"ren_hash",
+        90,
+        b"https://resp.uri",
+        b"ren_resp",
+        b"approved",
+    );
+    assert!(state.query_is_job_verified(b"job_ren"));
+
+    // Answered requests can no longer be expired
+    state.world.current_block().block_timestamp_seconds(2_000);
+    state.expire_validation_request_expect_err(
+        &CLIENT,
+        b"ren_hash",
+        "Validation request already reached its quorum",
+    );
+}
+
+// ============================================
+// 84. Request and response URIs are stored with the request
+// ============================================
+
+#[test]
+fn test_validation_uris_stored() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.init_job(&CLIENT, b"job_uri", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_uri", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_uri",
+        &VALIDATOR,
+        b"https://val.uri/request",
+        b"uri_hash",
+    );
+
+    let status = state.query_validation_status(b"uri_hash").unwrap();
+    assert_eq!(
+        status.request_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://val.uri/request")
+    );
+    assert!(status.response_uri.is_empty());
+
+    state.validation_response(
+        &VALIDATOR,
+        b"uri_hash",
+        90,
+        b"https://resp.uri/evidence",
+        b"uri_resp",
+        b"approved",
+    );
+
+    let status = state.query_validation_status(b"uri_hash").unwrap();
+    assert_eq!(
+        status.request_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://val.uri/request")
+    );
+    assert_eq!(
+        status.response_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://resp.uri/evidence")
+    );
+}
+
+// ============================================
+// 85. Upgrade migrates validation records stored without URIs
+// ============================================
+
+#[test]
+fn test_upgrade_migrates_validation_uris() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.init_job(&CLIENT, b"job_legacy", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_legacy", b"proof");
+    state.store_legacy_validation_request(1, b"job_legacy", b"legacy_hash", &VALIDATOR, 80);
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_legacy",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"current_hash",
+    );
+
+    state.upgrade_validation_migrating(&[1]);
+
+    let legacy = state.query_validation_status(b"legacy_hash").unwrap();
+    assert_eq!(
+        legacy.validator_address,
+        ManagedAddress::<StaticApi>::from(VALIDATOR.eval_to_array())
+    );
+    assert_eq!(legacy.response, 80);
+    assert_eq!(
+        legacy.response_hash,
+        ManagedBuffer::<StaticApi>::from(b"legacy_response_hash")
+    );
+    assert!(legacy.request_uri.is_empty());
+    assert!(legacy.response_uri.is_empty());
+
+    // Records already in the current layout are untouched
+    let current = state.query_validation_status(b"current_hash").unwrap();
+    assert_eq!(
+        current.request_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://val.uri")
+    );
+
+    // Migrating twice is harmless
+    state.upgrade_validation_migrating(&[1]);
+    assert_eq!(
+        state.query_validation_status(b"legacy_hash").unwrap(),
+        legacy
+    );
+}
+
+// ============================================
+// 86. Validation request/response events carry typed data
+// ============================================
+
+#[test]
+fn test_validation_events_typed_data() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_validation_request_timeout(100);
+    state.world.current_block().block_timestamp_seconds(1_000);
+
+    state.init_job(&CLIENT, b"job_evt", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_evt", b"proof");
+    let logs = state.validation_request_with_logs(
+        &AGENT_OWNER,
+        b"job_evt",
+        &VALIDATOR,
+        b"https://val.uri/evt",
+        b"evt_hash",
+    );
+
+    // Indexed hash topic holds the request hash, not the URI
+    let log = find_log(&logs, b"validationRequest");
+    assert_eq!(
+        log.topics,
+        vec![
+            b"validationRequest".to_vec(),
+            VALIDATOR.eval_to_array().to_vec(),
+            top_encode_to_vec_u8_or_panic(&1u64),
+            b"evt_hash".to_vec(),
+        ]
+    );
+    let expected = ValidationRequestEventData::<StaticApi> {
+        job_id: ManagedBuffer::from(b"job_evt"),
+        request_uri: ManagedBuffer::from(b"https://val.uri/evt"),
+        quorum: 1,
+        deadline: TimestampSeconds::new(1_100),
+    };
+    assert_eq!(log.data, vec![top_encode_to_vec_u8_or_panic(&expected)]);
+
+    state.world.current_block().block_timestamp_seconds(1_050);
+    let logs = state.validation_response_with_logs(
+        &VALIDATOR,
+        b"evt_hash",
+        90,
+        b"https://resp.uri/evt",
+        b"evt_resp",
+        b"approved",
+    );
+
+    let log = find_log(&logs, b"validationResponse");
+    assert_eq!(
+        log.topics,
+        vec![
+            b"validationResponse".to_vec(),
+            VALIDATOR.eval_to_array().to_vec(),
+            top_encode_to_vec_u8_or_panic(&1u64),
+            b"evt_hash".to_vec(),
+        ]
+    );
+    let expected = ValidationResponseEventData::<StaticApi> {
+        job_id: ManagedBuffer::from(b"job_evt"),
+        response: 90,
+        response_uri: ManagedBuffer::from(b"https://resp.uri/evt"),
+        response_hash: ManagedBuffer::from(b"evt_resp"),
+        tag: ManagedBuffer::from(b"approved"),
+        aggregate_response: 90,
+        quorum_reached: true,
+    };
+    assert_eq!(log.data, vec![top_encode_to_vec_u8_or_panic(&expected)]);
+
+    let log = find_log(&logs, b"jobStatusChanged");
+    assert_eq!(
+        log.topics,
+        vec![
+            b"jobStatusChanged".to_vec(),
+            b"job_evt".to_vec(),
+            top_encode_to_vec_u8_or_panic(&JobStatus::ValidationRequested),
+            top_encode_to_vec_u8_or_panic(&JobStatus::Verified),
+        ]
+    );
+    let expected = JobStatusChangedEventData {
+        agent_nonce: 1,
+        timestamp: TimestampSeconds::new(1_050),
+    };
+    assert_eq!(log.data, vec![top_encode_to_vec_u8_or_panic(&expected)]);
+}
+
+// ============================================
+// 87. Expiry and validator registry events carry typed data
+// ============================================
+
+#[test]
+fn test_expiry_and_validator_events_typed_data() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_validation_request_timeout(100);
+    state.set_expiry_grace_period(0);
+    state.world.current_block().block_timestamp_seconds(1_000);
+
+    state.init_job(&CLIENT, b"job_exp_evt", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_exp_evt", b"proof");
+    state.quorum_validation_request(
+        &AGENT_OWNER,
+        b"job_exp_evt",
+        vec![&VALIDATOR, &VALIDATOR_2],
+        2,
+        b"exp_evt_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"exp_evt_hash",
+        90,
+        b"https://resp.uri",
+        b"resp",
+        b"approved",
+    );
+
+    state.world.current_block().block_timestamp_seconds(1_101);
+    let logs = state.expire_validation_request_with_logs(&CLIENT, b"exp_evt_hash");
+    let log = find_log(&logs, b"validationRequestExpired");
+    assert_eq!(
+        log.topics,
+        vec![
+            b"validationRequestExpired".to_vec(),
+            b"exp_evt_hash".to_vec(),
+            b"job_exp_evt".to_vec(),
+        ]
+    );
+    let expected = ValidationRequestExpiredEventData {
+        quorum: 2,
+        response_count: 1,
+    };
+    assert_eq!(log.data, vec![top_encode_to_vec_u8_or_panic(&expected)]);
+
+    state.set_validator_staking(1_000, 100);
+    let logs = state.register_validator_with_logs(&VALIDATOR_3, 1_500, vec![b"code-review"]);
+    let log = find_log(&logs, b"validatorRegistered");
+    assert_eq!(
+        log.topics,
+        vec![
+            b"validatorRegistered".to_vec(),
+            VALIDATOR_3.eval_to_array().to_vec(),
+        ]
+    );
+    let expected = ValidatorStakeEventData::<StaticApi> {
+        token_identifier: EgldOrEsdtTokenIdentifier::egld(),
+        token_nonce: 0,
+        amount: BigUint::from(1_500u64),
+        total_stake: BigUint::from(1_500u64),
+    };
+    assert_eq!(log.data, vec![top_encode_to_vec_u8_or_panic(&expected)]);
+}
+
+// ============================================
+// 88. Request hashes are single-use, optionally bound to the request content
+// ============================================
+
+#[test]
+fn test_request_hash_integrity() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_validation_request_timeout(100);
+    state.set_expiry_grace_period(0);
+    state.world.current_block().block_timestamp_seconds(1_000);
+
+    state.init_job(&CLIENT, b"job_hash", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_hash", b"proof");
+
+    let content = b"{\"job\":\"job_hash\",\"proof\":\"proof\"}";
+    let content_hash = keccak256(content);
+    state.validation_request_with_content_expect_err(
+        &AGENT_OWNER,
+        b"job_hash",
+        &VALIDATOR,
+        b"not_the_content_hash",
+        content,
+        "Request hash does not match the keccak256 of the request content",
+    );
+    state.validation_request_with_content(
+        &AGENT_OWNER,
+        b"job_hash",
+        &VALIDATOR,
+        &content_hash,
+        content,
+    );
+    state.validation_response(
+        &VALIDATOR,
+        &content_hash,
+        90,
+        b"https://resp.uri",
+        b"resp",
+        b"approved",
+    );
+
+    // An answered request cannot be overwritten, from this job or another one
+    state.init_job(&CLIENT, b"job_hash_2", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_hash_2", b"proof");
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_hash_2",
+        &VALIDATOR_2,
+        b"https://val.uri",
+        &content_hash,
+        "Request hash already used",
+    );
+    let status = state.query_validation_status(&content_hash).unwrap();
+    assert_eq!(status.job_id, ManagedBuffer::<StaticApi>::from(b"job_hash"));
+    assert_eq!(status.response, 90);
+
+    // Expired requests keep their hash too; a retry needs a fresh one
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_hash_2",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"first_try",
+    );
+    state.world.current_block().block_timestamp_seconds(1_101);
+    state.expire_validation_request(&CLIENT, b"first_try");
+    state.validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_hash_2",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"first_try",
+        "Request hash already used",
+    );
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_hash_2",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"second_try",
+    );
+
+    assert_eq!(
+        state.query_job_validation_history(b"job_hash"),
+        vec![ManagedBuffer::<StaticApi>::from(content_hash.as_slice())]
+    );
+    assert_eq!(
+        state.query_job_validation_history(b"job_hash_2"),
+        vec![
+            ManagedBuffer::<StaticApi>::from(b"first_try"),
+            ManagedBuffer::<StaticApi>::from(b"second_try"),
+        ]
+    );
+
+    // Cleaning an old job, once no request is open, keeps its history
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(1_101 + 4 * 24 * 60 * 60 + 1);
+    state.expire_validation_request(&CLIENT, b"second_try");
+    state.clean_old_jobs(vec![b"job_hash_2"]);
+    assert!(state.query_job_data(b"job_hash_2").is_none());
+    assert_eq!(
+        state.query_job_validation_history(b"job_hash_2"),
+        vec![
+            ManagedBuffer::<StaticApi>::from(b"first_try"),
+            ManagedBuffer::<StaticApi>::from(b"second_try"),
+        ]
+    );
+}
+
+// ============================================
+// 89. Employer cancels a job the agent never started
+// ============================================
+
+#[test]
+fn test_cancel_job() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.cancel_job_expect_err(&CLIENT, b"job_missing", "Job not found");
+
+    state.init_job(&CLIENT, b"job_cancel", 1, None);
+    state.cancel_job_expect_err(
+        &AGENT_OWNER,
+        b"job_cancel",
+        "Only the employer can call this",
+    );
+
+    let logs = state.cancel_job_with_logs(&CLIENT, b"job_cancel");
+    let log = find_log(&logs, b"jobCancelled");
+    assert_eq!(
+        log.topics,
+        vec![
+            b"jobCancelled".to_vec(),
+            b"job_cancel".to_vec(),
+            CLIENT.eval_to_array().to_vec(),
+        ]
+    );
+    assert_eq!(
+        log.data,
+        vec![top_encode_to_vec_u8_or_panic(&JobCancelledEventData {
+            agent_nonce: 1
+        })]
+    );
+    let log = find_log(&logs, b"jobStatusChanged");
+    assert_eq!(
+        log.topics[3],
+        top_encode_to_vec_u8_or_panic(&JobStatus::Cancelled)
+    );
+
+    let job = state.query_job_data(b"job_cancel").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Cancelled);
+
+    // A cancelled job is closed for good
+    state.submit_proof_expect_err(
+        &AGENT_OWNER,
+        b"job_cancel",
+        b"proof",
+        "Job is already finalized",
+    );
+    state.cancel_job_expect_err(&CLIENT, b"job_cancel", "Job is already finalized");
+
+    // Once the agent has submitted a proof, the job can no longer be cancelled
+    state.init_job(&CLIENT, b"job_started", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_started", b"proof");
+    state.cancel_job_expect_err(
+        &CLIENT,
+        b"job_started",
+        "Only jobs without a submitted proof can be cancelled",
+    );
+}
+
+// ============================================
+// 90. Pay on verification — held payment released to the agent owner
+// ============================================
+
+#[test]
+fn test_pay_on_verification_release() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.set_service_pay_on_verification_expect_err(
+        &CLIENT,
+        1,
+        1,
+        true,
+        "Only the agent owner can perform this action",
+    );
+    state.set_service_pay_on_verification_expect_err(
+        &AGENT_OWNER,
+        1,
+        2,
+        true,
+        "Service config not found for agent",
+    );
+    state.set_service_pay_on_verification(&AGENT_OWNER, 1, 1, true);
+    assert!(state.query_service_pay_on_verification(1, 1));
+
+    state.init_job_with_payment(&CLIENT, b"job_pov", 1, 1, "USDC-abcdef", 0, 100);
+    state
+        .world
+        .check_account(VALIDATION_SC_ADDRESS)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 0u64);
+    let held = state.query_held_payment(b"job_pov").into_option().unwrap();
+    assert_eq!(held.payments.len(), 1);
+    assert_eq!(
+        held.payments.get(0).amount.as_big_uint(),
+        &BigUint::from(100u64)
+    );
+
+    state.submit_proof(&AGENT_OWNER, b"job_pov", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_pov",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"pov_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"pov_hash",
+        90,
+        b"https://resp.uri",
+        b"resp",
+        b"approved",
+    );
+
+    assert!(state.query_is_job_verified(b"job_pov"));
+    assert!(state.query_held_payment(b"job_pov").into_option().is_none());
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+    state
+        .world
+        .check_account(VALIDATION_SC_ADDRESS)
+        .esdt_balance(PAYMENT_TOKEN, 0u64);
+
+    // Switching the mode off forwards payments right away again
+    state.set_service_pay_on_verification(&AGENT_OWNER, 1, 1, false);
+    state.init_job_with_payment(&CLIENT, b"job_direct", 1, 1, "USDC-abcdef", 0, 100);
+    assert!(
+        state
+            .query_held_payment(b"job_direct")
+            .into_option()
+            .is_none()
+    );
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 200u64);
+}
+
+// ============================================
+// 91. Pay on verification — held payment refunded on rejection, cancellation or expiry
+// ============================================
+
+#[test]
+fn test_pay_on_verification_refunds() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.set_service_pay_on_verification(&AGENT_OWNER, 1, 1, true);
+    state.set_payment_hold_timeout(100);
+    state.world.current_block().block_timestamp_seconds(1_000);
+
+    // Rejected by the validator
+    state.init_job_with_payment(&CLIENT, b"job_rej", 1, 1, "USDC-abcdef", 0, 100);
+    state.submit_proof(&AGENT_OWNER, b"job_rej", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_rej",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"rej_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"rej_hash",
+        10,
+        b"https://resp.uri",
+        b"resp",
+        b"failed",
+    );
+    assert!(state.query_held_payment(b"job_rej").into_option().is_none());
+
+    // Cancelled by the employer
+    state.init_job_with_payment(&CLIENT, b"job_cxl", 1, 1, "USDC-abcdef", 0, 100);
+    let logs = state.cancel_job_with_logs(&CLIENT, b"job_cxl");
+    let log = find_log(&logs, b"heldPaymentRefunded");
+    let mut payments = ManagedVec::<StaticApi, Payment<StaticApi>>::new();
+    payments.push(Payment::new(
+        TokenId::from("USDC-abcdef"),
+        0,
+        NonZeroBigUint::new_or_panic(BigUint::from(100u64)),
+    ));
+    assert_eq!(
+        log.data,
+        vec![top_encode_to_vec_u8_or_panic(&HeldPaymentEventData {
+            agent_nonce: 1,
+            payments,
+            deadline: TimestampSeconds::new(1_100),
+        })]
+    );
+    assert!(state.query_held_payment(b"job_cxl").into_option().is_none());
+
+    // Never verified before the hold ran out
+    state.init_job_with_payment(&CLIENT, b"job_hold", 1, 1, "USDC-abcdef", 0, 100);
+    state.submit_proof(&AGENT_OWNER, b"job_hold", b"proof");
+    state.refund_held_payment_expect_err(&WORKER, b"job_hold", "Payment hold has not expired yet");
+    state.world.current_block().block_timestamp_seconds(1_101);
+    state.refund_held_payment(&WORKER, b"job_hold");
+    let job = state.query_job_data(b"job_hold").into_option().unwrap();
+    assert_eq!(job.status, JobStatus::Expired);
+    state.refund_held_payment_expect_err(
+        &WORKER,
+        b"job_hold",
+        "No service payment held for this job",
+    );
+
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 0u64);
+}
+
+// ============================================
+// 92. Service accepting several tokens at different prices
+// ============================================
+
+#[test]
+fn test_service_accepts_several_tokens() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![
+            (1u32, 100u64, b"USDC-abcdef", 0u64),
+            (1u32, 50u64, b"EGLD-000000", 0u64),
+        ],
+    );
+
+    let payments = state.query_service_payments(1, 1);
+    assert_eq!(payments.len(), 2);
+    assert_eq!(
+        payments[0].token_identifier,
+        EgldOrEsdtTokenIdentifier::esdt("USDC-abcdef")
+    );
+    assert_eq!(payments[0].amount, BigUint::from(100u64));
+    assert_eq!(
+        payments[1].token_identifier,
+        EgldOrEsdtTokenIdentifier::egld()
+    );
+    assert_eq!(payments[1].amount, BigUint::from(50u64));
+
+    // The single-config view keeps returning the first accepted payment
+    let svc = state.query_service_config(1, 1).into_option().unwrap();
+    assert_eq!(svc.amount, BigUint::from(100u64));
+
+    state.init_job_with_payments(&CLIENT, b"job_egld", 1, 1, &[("EGLD-000000", 50)]);
+    state.init_job_with_payments(&CLIENT, b"job_usdc", 1, 1, &[("USDC-abcdef", 100)]);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_050u64)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+
+    state.init_job_with_payments_expect_err(
+        &CLIENT,
+        b"job_cheap",
+        1,
+        1,
+        &[("EGLD-000000", 49)],
+        "Insufficient payment",
+    );
+    state.init_job_with_payments_expect_err(
+        &CLIENT,
+        b"job_wrong",
+        1,
+        1,
+        &[("WRONG-abcdef", 100)],
+        "Invalid payment token",
+    );
+
+    // A new config call replaces the accepted list
+    state.set_service_configs(&AGENT_OWNER, 1, vec![(1u32, 200u64, b"USDC-abcdef", 0u64)]);
+    let payments = state.query_service_payments(1, 1);
+    assert_eq!(payments.len(), 1);
+    assert_eq!(payments[0].amount, BigUint::from(200u64));
+
+    state.set_service_configs_expect_err(
+        &AGENT_OWNER,
+        1,
+        vec![
+            (1u32, 100u64, b"USDC-abcdef", 0u64),
+            (1u32, 150u64, b"USDC-abcdef", 0u64),
+        ],
+        "Token listed more than once for the same service",
+    );
+}
+
+// ============================================
+// 93. Multi-transfer job payments
+// ============================================
+
+#[test]
+fn test_init_job_with_multi_transfer() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![
+            (1u32, 100u64, b"USDC-abcdef", 0u64),
+            (1u32, 50u64, b"EGLD-000000", 0u64),
+        ],
+    );
+
+    // Transfers in the same token add up to its price
+    state.init_job_with_payments(
+        &CLIENT,
+        b"job_split",
+        1,
+        1,
+        &[("USDC-abcdef", 60), ("USDC-abcdef", 40)],
+    );
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+
+    // Partial amounts in different tokens do not cover the price
+    state.init_job_with_payments_expect_err(
+        &CLIENT,
+        b"job_mixed",
+        1,
+        1,
+        &[("USDC-abcdef", 60), ("EGLD-000000", 20)],
+        "Insufficient payment",
+    );
+    // Every transfer must be in an accepted token
+    state.init_job_with_payments_expect_err(
+        &CLIENT,
+        b"job_extra",
+        1,
+        1,
+        &[("USDC-abcdef", 100), ("WRONG-abcdef", 1)],
+        "Invalid payment token",
+    );
+
+    // Held payments keep every transfer and return them all on refund
+    state.set_service_pay_on_verification(&AGENT_OWNER, 1, 1, true);
+    state.init_job_with_payments(
+        &CLIENT,
+        b"job_held",
+        1,
+        1,
+        &[("USDC-abcdef", 100), ("EGLD-000000", 10)],
+    );
+    let held = state.query_held_payment(b"job_held").into_option().unwrap();
+    assert_eq!(held.payments.len(), 2);
+    state
+        .world
+        .check_account(CLIENT)
+        .balance(1_000_000u64 - 10)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 200);
+
+    state.cancel_job(&CLIENT, b"job_held");
+    state
+        .world
+        .check_account(CLIENT)
+        .balance(1_000_000u64)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 100);
+}
+
+// ============================================
+// 94. Protocol fee on service payments, claimed to the treasury
+// ============================================
+
+#[test]
+fn test_protocol_fee_on_service_payments() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![
+            (1u32, 1_000u64, b"USDC-abcdef", 0u64),
+            (1u32, 500u64, b"EGLD-000000", 0u64),
+        ],
+    );
+    state.set_protocol_fee(250);
+
+    // 2.5% of each forwarded payment stays in the contract, rounded down
+    state.init_job_with_payment(&CLIENT, b"job_usdc", 1, 1, "USDC-abcdef", 0, 1_000);
+    state.init_job_with_payments(&CLIENT, b"job_egld", 1, 1, &[("EGLD-000000", 500)]);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 488)
+        .esdt_balance(PAYMENT_TOKEN, 975u64);
+
+    // Held payments pay the fee when released
+    state.set_service_pay_on_verification(&AGENT_OWNER, 1, 1, true);
+    state.init_job_with_payment(&CLIENT, b"job_held", 1, 1, "USDC-abcdef", 0, 1_000);
+    let held = state.query_held_payment(b"job_held").into_option().unwrap();
+    assert_eq!(held.protocol_fee_bps, 250);
+
+    // ...at the rate recorded when the job was opened
+    state.set_protocol_fee(1_000);
+    state.submit_proof(&AGENT_OWNER, b"job_held", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_held",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"held_hash",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"held_hash",
+        90,
+        b"https://resp.uri",
+        b"resp",
+        b"approved",
+    );
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 1_950u64);
+
+    let fees = state.query_accumulated_fees();
+    assert_eq!(fees.len(), 2);
+    assert_eq!(fees[0].token_identifier, TokenId::from("USDC-abcdef"));
+    assert_eq!(fees[0].amount.as_big_uint(), &BigUint::from(50u64));
+    assert_eq!(fees[1].token_identifier, TokenId::from("EGLD-000000"));
+    assert_eq!(fees[1].amount.as_big_uint(), &BigUint::from(12u64));
+
+    state.claim_fees_expect_err(&OWNER_ADDRESS, "Treasury address is not set");
+    state.set_treasury(&TREASURY);
+    state.claim_fees_expect_err(&CLIENT, "Only the owner or the treasury can claim fees");
+    let logs = state.claim_fees_with_logs(&TREASURY);
+    let log = find_log(&logs, b"feesClaimed");
+    let expected = FeesClaimedEventData {
+        payments: ManagedVec::<StaticApi, Payment<StaticApi>>::from_iter(fees),
+    };
+    assert_eq!(log.data, vec![top_encode_to_vec_u8_or_panic(&expected)]);
+    state
+        .world
+        .check_account(TREASURY)
+        .balance(12u64)
+        .esdt_balance(PAYMENT_TOKEN, 50u64);
+    assert!(state.query_accumulated_fees().is_empty());
+    state.claim_fees_expect_err(&OWNER_ADDRESS, "No fees to claim");
+}
+
+// ============================================
+// 95. Signed proof — replay protection
+// ============================================
+
+#[test]
+fn test_submit_signed_proof_replay() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"WalletlessBot",
+        b"https://example.com/manifest",
+        &agent_public_key(&AGENT_KEY_SEED),
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_replay", 1, None);
+    assert_eq!(state.query_proof_nonce(b"job_replay"), 0);
+
+    // A signature made for another contract does not verify here
+    let foreign = sign_proof_for(
+        &IDENTITY_SC_ADDRESS.eval_to_array(),
+        &AGENT_KEY_SEED,
+        b"job_replay",
+        0,
+        b"proof-a",
+    );
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_replay",
+        b"proof-a",
+        &foreign,
+        "ed25519 verify error",
+    );
+
+    let signature_a = sign_proof(&AGENT_KEY_SEED, b"job_replay", 0, b"proof-a");
+    state.submit_signed_proof(&WORKER, b"job_replay", b"proof-a", &signature_a);
+    assert_eq!(state.query_proof_nonce(b"job_replay"), 1);
+
+    let signature_b = sign_proof(&AGENT_KEY_SEED, b"job_replay", 1, b"proof-b");
+    state.submit_signed_proof(&WORKER, b"job_replay", b"proof-b", &signature_b);
+
+    // Replaying the first signature cannot roll the proof back
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_replay",
+        b"proof-a",
+        &signature_a,
+        "ed25519 verify error",
+    );
+    // Direct submissions bump the nonce too
+    state.submit_proof(&AGENT_OWNER, b"job_replay", b"proof-c");
+    state.submit_signed_proof_expect_err(
+        &WORKER,
+        b"job_replay",
+        b"proof-b",
+        &signature_b,
+        "ed25519 verify error",
+    );
+    assert_eq!(state.query_proof_nonce(b"job_replay"), 3);
+
+    let job = state.query_job_data(b"job_replay").into_option().unwrap();
+    assert_eq!(job.proof, ManagedBuffer::<StaticApi>::from(b"proof-c"));
+}
+
+// ============================================
+// 96. Milestone jobs stay open across deliverables
+// ============================================
+
+#[test]
+fn test_milestone_job_stays_open() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 1_000u64, b"USDC-abcdef", 0u64)],
+    );
+    state.init_job(&CLIENT, b"job_ms", 1, None);
+    state.init_job(&CLIENT, b"job_started", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_started", b"proof");
+    state.set_service_pay_on_verification(&AGENT_OWNER, 1, 1, true);
+    state.init_job_with_payment(&CLIENT, b"job_held", 1, 1, "USDC-abcdef", 0, 1_000);
+
+    state.enable_job_milestones_expect_err(
+        &AGENT_OWNER,
+        b"job_ms",
+        "Only the employer can call this",
+    );
+    state.enable_job_milestones_expect_err(
+        &CLIENT,
+        b"job_started",
+        "Milestones must be enabled before the first proof",
+    );
+    state.enable_job_milestones_expect_err(
+        &CLIENT,
+        b"job_held",
+        "Jobs holding a service payment cannot use milestones",
+    );
+    state.enable_job_milestones(&CLIENT, b"job_ms");
+    assert!(state.query_is_milestone_job(b"job_ms"));
+
+    // Each decided deliverable hands the job back for the next proof
+    for (proof, request_hash, score) in [
+        (b"proof_1", b"deliverable_1", 90u8),
+        (b"proof_2", b"deliverable_2", 10u8),
+        (b"proof_3", b"deliverable_3", 90u8),
+    ] {
+        state.submit_proof(&AGENT_OWNER, b"job_ms", proof);
+        state.validation_request(
+            &AGENT_OWNER,
+            b"job_ms",
+            &VALIDATOR,
+            b"https://val.uri",
+            request_hash,
+        );
+        state.validation_response(
+            &VALIDATOR,
+            request_hash,
+            score,
+            b"https://resp.uri",
+            b"resp",
+            b"tag",
+        );
+        assert_eq!(
+            state
+                .query_job_data(b"job_ms")
+                .into_option()
+                .unwrap()
+                .status,
+            JobStatus::Pending
+        );
+    }
+    assert!(state.query_is_request_passed(b"deliverable_1"));
+    assert!(!state.query_is_request_passed(b"deliverable_2"));
+    assert!(state.query_is_request_passed(b"deliverable_3"));
+    assert!(!state.query_is_job_verified(b"job_ms"));
+}
+
+// ============================================
+// 97. Legacy wallet-held agent NFT — custody before update/deregister
+// ============================================
+
+#[test]
+fn test_custody_legacy_agent_nft() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"LegacyBot",
+        b"https://example.com/manifest",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.move_agent_nft_to_owner(1);
+
+    // Recreate and burn need the NFT in the registry's balance
+    state.update_agent_expect_err(
+        &AGENT_OWNER,
+        1,
+        b"RenamedBot",
+        b"https://example.com/v2",
+        b"pubkey123",
+        "Agent NFT is held in a wallet; deposit it with custody_agent_nft first",
+    );
+    state.deregister_agent_expect_err(
+        &AGENT_OWNER,
+        1,
+        "Agent NFT is held in a wallet; deposit it with custody_agent_nft first",
+    );
+
+    // The wallet NFT can still move, but its holder gains no control over the agent
+    state.transfer_agent_nft(&AGENT_OWNER, &CLIENT, 1);
+    assert_eq!(state.query_agent_owner(1), AGENT_OWNER.to_managed_address());
+    state.set_metadata_expect_err(
+        &CLIENT,
+        1,
+        vec![(b"key", b"value")],
+        "Only the agent owner can perform this action",
+    );
+    state.custody_agent_nft_expect_err(
+        &CLIENT,
+        AGENT_TOKEN,
+        1,
+        1,
+        "Only the agent owner can perform this action",
+    );
+    state.transfer_agent_nft(&CLIENT, &AGENT_OWNER, 1);
+
+    state.custody_agent_nft_expect_err(
+        &CLIENT,
+        PAYMENT_TOKEN,
+        0,
+        1,
+        "Payment must be an agent identity NFT",
+    );
+
+    state.custody_agent_nft(&AGENT_OWNER, 1);
+    state
+        .world
+        .check_account(IDENTITY_SC_ADDRESS)
+        .esdt_nft_balance_and_attributes(
+            AGENT_TOKEN,
+            1,
+            1u64,
+            AgentDetails::<StaticApi> {
+                name: ManagedBuffer::from(b"LegacyBot"),
+                public_key: ManagedBuffer::from(b"pubkey123"),
+            },
+        );
+    state.transfer_agent_nft_expect_err(&AGENT_OWNER, &CLIENT, 1, "insufficient funds");
+
+    state.update_agent_raw(
+        &AGENT_OWNER,
+        1,
+        b"RenamedBot",
+        b"https://example.com/v2",
+        b"pubkey123",
+        None,
+        None,
+    );
+    state.deregister_agent(&AGENT_OWNER, 1);
+    state.check_agent_retired(1);
+}
+
+// ============================================
+// 98. Cleaning skips milestone jobs and jobs with open requests
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_skips_open_jobs() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job(&CLIENT, b"job_ms", 1, None);
+    state.enable_job_milestones(&CLIENT, b"job_ms");
+    state.init_job(&CLIENT, b"job_requested", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_requested", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_requested",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"req_hash",
+    );
+
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+    state.clean_old_jobs(vec![b"job_ms", b"job_requested"]);
+    assert!(state.query_job_data(b"job_ms").is_some());
+    assert!(state.query_job_data(b"job_requested").is_some());
+
+    // Once its request is decided the job can go, but its validation history stays
+    state.validation_response(
+        &VALIDATOR,
+        b"req_hash",
+        0,
+        b"https://resp.uri",
+        b"resp_hash",
+        b"failed",
+    );
+    state.clean_old_jobs(vec![b"job_ms", b"job_requested"]);
+    assert!(state.query_job_data(b"job_ms").is_some());
+    assert!(state.query_job_data(b"job_requested").is_none());
+    assert_eq!(
+        state.query_job_validation_history(b"job_requested"),
+        vec![ManagedBuffer::<StaticApi>::from(b"req_hash")]
+    );
+}
+
+// ============================================
+// 99. Legacy owners migrated by the owner without an upgrade
+// ============================================
+
+#[test]
+fn test_migrate_legacy_agents_endpoint() {
+    let mut state = AgentTestState::new();
+    state.seed_legacy_agent(7, &AGENT_OWNER);
+    state.seed_legacy_agent(8, &CLIENT);
+
+    state.migrate_legacy_agents_expect_err(
+        &CLIENT,
+        &[7, 8],
+        "Endpoint can only be called by owner",
+    );
+
+    state.migrate_legacy_agents(&OWNER_ADDRESS, &[7]);
+    assert_eq!(state.query_agent_owner(7), AGENT_OWNER.to_managed_address());
+    assert!(state.query_agents_by_owner(&CLIENT, 0, 10).is_empty());
+
+    // Further batches need no upgrade; migrated and unknown nonces are skipped
+    state.migrate_legacy_agents(&OWNER_ADDRESS, &[7, 8, 9]);
+    assert_eq!(state.query_agent_owner(8), CLIENT.to_managed_address());
+    assert_eq!(state.query_agents_by_owner(&AGENT_OWNER, 0, 10), vec![7u64]);
+    assert_eq!(state.query_agents_by_owner(&CLIENT, 0, 10), vec![8u64]);
+}
+
+// ============================================
+// 100. Unmigrated legacy owners still count towards the one-agent limit
+// ============================================
+
+#[test]
+fn test_legacy_owner_cannot_own_second_agent() {
+    let mut state = AgentTestState::new();
+    state.seed_legacy_agent(7, &AGENT_OWNER);
+
+    state.register_agent_expect_err(
+        &AGENT_OWNER,
+        b"SecondBot",
+        b"https://example.com/second",
+        b"pubkey456",
+        "Agent already registered for this address",
+    );
+    state.register_agent(
+        &CLIENT,
+        b"ClientBot",
+        b"https://example.com/c",
+        b"pubkey-c",
+        vec![],
+        vec![],
+    );
+    state.propose_agent_transfer(&CLIENT, 1, &AGENT_OWNER);
+    state.accept_agent_transfer_expect_err(
+        &AGENT_OWNER,
+        1,
+        "Agent already registered for this address",
+    );
+
+    // Multi-agent mode lifts the limit as for migrated owners
+    state.set_multi_agent_mode(true);
+    state.accept_agent_transfer(&AGENT_OWNER, 1);
+    assert_eq!(state.query_agent_owner(1), AGENT_OWNER.to_managed_address());
+}
+
+// ============================================
+// 101. The agent's owner and operators cannot validate its jobs
+// ============================================
+
+#[test]
+fn test_validator_cannot_be_agent_owner_or_operator() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_agent_operator(&AGENT_OWNER, 1, &WORKER, PERMISSION_SUBMIT_PROOF);
+    state.set_validation_request_timeout(100);
+    state.world.current_block().block_timestamp_seconds(1_000);
+    state.init_job(&CLIENT, b"job_self", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_self", b"proof");
+
+    for validator in [&AGENT_OWNER, &WORKER] {
+        state.validation_request_expect_err(
+            &AGENT_OWNER,
+            b"job_self",
+            validator,
+            b"https://val.uri",
+            b"self_hash",
+            "Validator cannot be the agent owner or one of its operators",
+        );
+    }
+    state.quorum_validation_request_expect_err(
+        &AGENT_OWNER,
+        b"job_self",
+        vec![&VALIDATOR, &WORKER],
+        1,
+        b"self_hash",
+        "Validator cannot be the agent owner or one of its operators",
+    );
+
+    // Nor can they take over from a silent validator
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_self",
+        &VALIDATOR,
+        b"https://val.uri",
+        b"self_hash",
+    );
+    state.world.current_block().block_timestamp_seconds(1_101);
+    state.renominate_validator_expect_err(
+        &AGENT_OWNER,
+        b"self_hash",
+        &VALIDATOR,
+        &AGENT_OWNER,
+        "Validator cannot be the agent owner or one of its operators",
+    );
+    state.renominate_validator_expect_err(
+        &AGENT_OWNER,
+        b"self_hash",
+        &VALIDATOR,
+        &WORKER,
+        "Validator cannot be the agent owner or one of its operators",
+    );
+    state.renominate_validator(&AGENT_OWNER, b"self_hash", &VALIDATOR, &VALIDATOR_2);
+}
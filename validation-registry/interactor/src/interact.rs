@@ -136,7 +136,7 @@ impl ContractInteract {
             .from(&self.wallet_address)
             .gas(30_000_000u64)
             .typed(validation_registry_proxy::ValidationRegistryProxy)
-            .upgrade()
+            .upgrade(MultiValueVec::<u64>::new())
             .code(&self.contract_code)
             .code_metadata(CodeMetadata::UPGRADEABLE)
             .returns(ReturnsResultUnmanaged)
@@ -207,7 +207,13 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(validation_registry_proxy::ValidationRegistryProxy)
-            .validation_request(job_id, validator_address, request_uri, request_hash)
+            .validation_request(
+                job_id,
+                validator_address,
+                request_uri,
+                request_hash,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -1,5 +1,7 @@
 multiversx_sc::imports!();
 
+use crate::errors::ERR_INVALID_THRESHOLD;
+
 #[multiversx_sc::module]
 pub trait ConfigModule:
     common::cross_contract::CrossContractModule + crate::storage::ExternalStorageModule
@@ -9,4 +11,54 @@ pub trait ConfigModule:
     fn set_identity_registry_address(&self, address: ManagedAddress) {
         self.identity_registry_address().set(&address);
     }
+
+    /// Escrow contract consulted by `clean_old_jobs`, so jobs with funds still in escrow
+    /// are not cleaned.
+    #[only_owner]
+    #[endpoint(set_escrow_contract_address)]
+    fn set_escrow_contract_address(&self, address: ManagedAddress) {
+        self.escrow_contract_address().set(&address);
+    }
+
+    /// Deadline given to validators on new requests (and on re-nomination).
+    #[only_owner]
+    #[endpoint(set_validation_request_timeout)]
+    fn set_validation_request_timeout(&self, timeout: DurationSeconds) {
+        self.validation_request_timeout().set(timeout);
+    }
+
+    /// How long after a request's deadline only the agent may act (by renominating),
+    /// before anyone can expire the request.
+    #[only_owner]
+    #[endpoint(set_expiry_grace_period)]
+    fn set_expiry_grace_period(&self, grace_period: DurationSeconds) {
+        self.expiry_grace_period().set(grace_period);
+    }
+
+    /// How long validators have to answer before an escrowed fee becomes refundable.
+    /// Applies to requests opened afterwards.
+    #[only_owner]
+    #[endpoint(set_validator_fee_timeout)]
+    fn set_validator_fee_timeout(&self, timeout: DurationSeconds) {
+        self.validator_fee_timeout().set(timeout);
+    }
+
+    /// How long a held service payment waits for verification before the employer can
+    /// reclaim it. Applies to jobs opened afterwards.
+    #[only_owner]
+    #[endpoint(set_payment_hold_timeout)]
+    fn set_payment_hold_timeout(&self, timeout: DurationSeconds) {
+        self.payment_hold_timeout().set(timeout);
+    }
+
+    /// Global pass threshold (0-100). Agents may only raise it per service.
+    #[only_owner]
+    #[endpoint(set_pass_threshold)]
+    fn set_pass_threshold(&self, threshold: u8) {
+        require!(
+            threshold <= common::structs::MAX_VALIDATION_SCORE,
+            ERR_INVALID_THRESHOLD
+        );
+        self.pass_threshold().set(threshold);
+    }
 }
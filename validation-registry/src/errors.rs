@@ -1,3 +1,6 @@
+pub use common::errors::ERR_AGENT_NOT_FOUND;
+pub use common::errors::ERR_AGENT_PAUSED;
+pub use common::errors::ERR_INVALID_THRESHOLD;
 pub use common::errors::ERR_JOB_NOT_FOUND;
 pub use common::errors::ERR_NOT_AGENT_OWNER;
 
@@ -7,4 +10,45 @@ pub const ERR_SERVICE_NOT_FOUND: &str = "Service config not found for agent";
 pub const ERR_INVALID_PAYMENT: &str = "Invalid payment token";
 pub const ERR_VALIDATION_REQUEST_NOT_FOUND: &str = "Validation request not found";
 pub const ERR_NOT_VALIDATOR: &str = "Only the designated validator can respond";
-pub const ERR_INVALID_AGENT_NFT: &str = "Invalid agent NFT: wrong token ID or nonce";
+pub const ERR_INVALID_PUBLIC_KEY: &str = "Agent public key is not a valid Ed25519 key";
+pub const ERR_PROOF_LOCKED: &str = "Proof cannot be changed once validation is requested";
+pub const ERR_PROOF_REQUIRED: &str = "Proof must be submitted before requesting validation";
+pub const ERR_JOB_FINALIZED: &str = "Job is already finalized";
+pub const ERR_VALIDATION_NOT_REQUESTED: &str = "Job is not awaiting validation";
+pub const ERR_NOT_EMPLOYER: &str = "Only the employer can call this";
+pub const ERR_JOB_NOT_CANCELLABLE: &str = "Only jobs without a submitted proof can be cancelled";
+pub const ERR_JOB_ALREADY_STARTED: &str = "Milestones must be enabled before the first proof";
+pub const ERR_MILESTONES_WITH_HELD_PAYMENT: &str =
+    "Jobs holding a service payment cannot use milestones";
+pub const ERR_INVALID_STATUS_TRANSITION: &str = "Invalid job status transition";
+pub const ERR_INVALID_RESPONSE: &str = "Response must not exceed the maximum validation score";
+pub const ERR_INVALID_QUORUM: &str = "Quorum must be between 1 and the number of validators";
+pub const ERR_TOO_MANY_VALIDATORS: &str = "Too many validators for one request";
+pub const ERR_DUPLICATE_VALIDATOR: &str = "Validator nominated more than once";
+pub const ERR_VALIDATOR_IS_AGENT: &str =
+    "Validator cannot be the agent owner or one of its operators";
+pub const ERR_REQUEST_EXPIRED: &str = "Validation request has expired";
+pub const ERR_REQUEST_DEADLINE_NOT_PASSED: &str = "Validation request deadline has not passed yet";
+pub const ERR_EXPIRY_GRACE_NOT_PASSED: &str =
+    "Validation request is still in its renomination grace period";
+pub const ERR_QUORUM_ALREADY_REACHED: &str = "Validation request already reached its quorum";
+pub const ERR_DUPLICATE_REQUEST_HASH: &str = "Request hash already used";
+pub const ERR_REQUEST_HASH_MISMATCH: &str =
+    "Request hash does not match the keccak256 of the request content";
+pub const ERR_VALIDATOR_ALREADY_RESPONDED: &str = "Validator has already responded";
+pub const ERR_FEE_ALREADY_ESCROWED: &str = "A validation fee is already escrowed for this request";
+pub const ERR_FEE_NOT_FOUND: &str = "No validation fee escrowed for this request";
+pub const ERR_FEE_TIMEOUT_NOT_PASSED: &str = "Validation fee timeout has not passed yet";
+pub const ERR_NO_HELD_PAYMENT: &str = "No service payment held for this job";
+pub const ERR_PAYMENT_HOLD_NOT_EXPIRED: &str = "Payment hold has not expired yet";
+pub const ERR_STAKING_NOT_CONFIGURED: &str = "Validator staking is not configured";
+pub const ERR_VALIDATOR_ALREADY_REGISTERED: &str = "Validator already registered";
+pub const ERR_VALIDATOR_NOT_REGISTERED: &str = "Validator not registered";
+pub const ERR_INVALID_STAKE_TOKEN: &str = "Invalid stake token";
+pub const ERR_INSUFFICIENT_STAKE: &str = "Stake below the minimum";
+pub const ERR_VALIDATOR_NOT_ACTIVE: &str = "Validator is not active";
+pub const ERR_VALIDATOR_NOT_UNBONDING: &str = "Validator is not unbonding";
+pub const ERR_UNBONDING_NOT_FINISHED: &str = "Unbonding period has not passed yet";
+pub const ERR_VALIDATOR_NOT_ELIGIBLE: &str = "Validator is not active or not sufficiently staked";
+pub const ERR_NO_RESPONSE_TO_SLASH: &str = "Validator did not respond to this request";
+pub const ERR_INVALID_SLASH_AMOUNT: &str = "Slash amount must be positive and within the stake";
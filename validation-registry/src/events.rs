@@ -1,25 +1,243 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::structs::ValidationRequestData;
+use crate::structs::JobStatus;
+
+/// ERC-8004 validation request event data — one event per nominated validator.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidationRequestEventData<M: ManagedTypeApi> {
+    pub job_id: ManagedBuffer<M>,
+    pub request_uri: ManagedBuffer<M>,
+    pub quorum: u32,
+    pub deadline: TimestampSeconds,
+}
+
+/// ERC-8004 validation response event data. `response` is the emitting validator's answer,
+/// `aggregate_response` the request's median after it.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidationResponseEventData<M: ManagedTypeApi> {
+    pub job_id: ManagedBuffer<M>,
+    pub response: u8,
+    pub response_uri: ManagedBuffer<M>,
+    pub response_hash: ManagedBuffer<M>,
+    pub tag: ManagedBuffer<M>,
+    pub aggregate_response: u8,
+    pub quorum_reached: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobStatusChangedEventData {
+    pub agent_nonce: u64,
+    pub timestamp: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobCancelledEventData {
+    pub agent_nonce: u64,
+}
+
+/// Held service payment: held at `init_job`, then released or refunded. `deadline` is the
+/// end of the hold.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct HeldPaymentEventData<M: ManagedTypeApi> {
+    pub agent_nonce: u64,
+    pub payments: ManagedVec<M, Payment<M>>,
+    pub deadline: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidationRequestExpiredEventData {
+    pub quorum: u32,
+    pub response_count: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorRenominatedEventData<M: ManagedTypeApi> {
+    pub job_id: ManagedBuffer<M>,
+    pub deadline: TimestampSeconds,
+}
+
+/// Validator fee movement: escrowed, paid to a validator or refunded to the payer.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorFeeEventData<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+}
+
+/// Validator stake movement. `total_stake` is the validator's stake after it.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorStakeEventData<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+    pub total_stake: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorTagsEventData<M: ManagedTypeApi> {
+    pub tags: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ValidatorUnbondingEventData<M: ManagedTypeApi> {
+    pub unbond_timestamp: TimestampSeconds,
+    pub stake: BigUint<M>,
+}
 
 #[multiversx_sc::module]
 pub trait EventsModule {
     #[event("validationRequest")]
     fn validation_request_event(
         &self,
-        #[indexed] validator_address: ManagedAddress,
+        #[indexed] validator_address: &ManagedAddress,
         #[indexed] agent_nonce: u64,
-        #[indexed] request_hash: ManagedBuffer,
-        request_uri: ManagedBuffer,
+        #[indexed] request_hash: &ManagedBuffer,
+        data: ValidationRequestEventData<Self::Api>,
+    );
+
+    #[event("jobStatusChanged")]
+    fn job_status_changed_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] previous_status: JobStatus,
+        #[indexed] new_status: JobStatus,
+        data: JobStatusChangedEventData,
+    );
+
+    #[event("jobCancelled")]
+    fn job_cancelled_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        data: JobCancelledEventData,
+    );
+
+    #[event("paymentHeld")]
+    fn payment_held_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        data: HeldPaymentEventData<Self::Api>,
+    );
+
+    #[event("heldPaymentReleased")]
+    fn held_payment_released_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] agent_owner: &ManagedAddress,
+        data: HeldPaymentEventData<Self::Api>,
+    );
+
+    #[event("heldPaymentRefunded")]
+    fn held_payment_refunded_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        data: HeldPaymentEventData<Self::Api>,
     );
 
     #[event("validationResponse")]
     fn validation_response_event(
         &self,
-        #[indexed] validator_address: ManagedAddress,
+        #[indexed] validator_address: &ManagedAddress,
         #[indexed] agent_nonce: u64,
-        #[indexed] request_hash: ManagedBuffer,
-        data: ValidationRequestData<Self::Api>,
+        #[indexed] request_hash: &ManagedBuffer,
+        data: ValidationResponseEventData<Self::Api>,
+    );
+
+    #[event("validationRequestExpired")]
+    fn validation_request_expired_event(
+        &self,
+        #[indexed] request_hash: &ManagedBuffer,
+        #[indexed] job_id: &ManagedBuffer,
+        data: ValidationRequestExpiredEventData,
+    );
+
+    #[event("validatorRenominated")]
+    fn validator_renominated_event(
+        &self,
+        #[indexed] request_hash: &ManagedBuffer,
+        #[indexed] previous_validator: &ManagedAddress,
+        #[indexed] new_validator: &ManagedAddress,
+        data: ValidatorRenominatedEventData<Self::Api>,
+    );
+
+    #[event("validatorFeeEscrowed")]
+    fn validator_fee_escrowed_event(
+        &self,
+        #[indexed] request_hash: &ManagedBuffer,
+        #[indexed] payer: &ManagedAddress,
+        data: ValidatorFeeEventData<Self::Api>,
+    );
+
+    #[event("validatorFeePaid")]
+    fn validator_fee_paid_event(
+        &self,
+        #[indexed] request_hash: &ManagedBuffer,
+        #[indexed] validator: &ManagedAddress,
+        data: ValidatorFeeEventData<Self::Api>,
+    );
+
+    #[event("validatorFeeRefunded")]
+    fn validator_fee_refunded_event(
+        &self,
+        #[indexed] request_hash: &ManagedBuffer,
+        #[indexed] payer: &ManagedAddress,
+        data: ValidatorFeeEventData<Self::Api>,
+    );
+
+    #[event("validatorRegistered")]
+    fn validator_registered_event(
+        &self,
+        #[indexed] validator: &ManagedAddress,
+        data: ValidatorStakeEventData<Self::Api>,
+    );
+
+    #[event("validatorStakeAdded")]
+    fn validator_stake_added_event(
+        &self,
+        #[indexed] validator: &ManagedAddress,
+        data: ValidatorStakeEventData<Self::Api>,
+    );
+
+    #[event("validatorTagsUpdated")]
+    fn validator_tags_updated_event(
+        &self,
+        #[indexed] validator: &ManagedAddress,
+        data: ValidatorTagsEventData<Self::Api>,
+    );
+
+    #[event("validatorUnbonding")]
+    fn validator_unbonding_event(
+        &self,
+        #[indexed] validator: &ManagedAddress,
+        data: ValidatorUnbondingEventData<Self::Api>,
+    );
+
+    #[event("validatorWithdrawn")]
+    fn validator_withdrawn_event(
+        &self,
+        #[indexed] validator: &ManagedAddress,
+        data: ValidatorStakeEventData<Self::Api>,
+    );
+
+    #[event("validatorSlashed")]
+    fn validator_slashed_event(
+        &self,
+        #[indexed] validator: &ManagedAddress,
+        #[indexed] request_hash: &ManagedBuffer,
+        data: ValidatorStakeEventData<Self::Api>,
     );
 }
@@ -8,13 +8,25 @@ pub mod errors;
 pub mod events;
 pub mod storage;
 pub mod structs;
+pub mod utils;
+pub mod validators;
 pub mod views;
 
 pub use structs::*;
 
 use errors::*;
+use events::{
+    HeldPaymentEventData, JobCancelledEventData, ValidationRequestExpiredEventData,
+    ValidationResponseEventData, ValidatorFeeEventData, ValidatorRenominatedEventData,
+};
 
 const THREE_DAYS: DurationMillis = DurationMillis::new(3 * 24 * 60 * 60 * 1000);
+const ED25519_PUBLIC_KEY_LENGTH: usize = 32;
+const DEFAULT_PASS_THRESHOLD: u8 = 50;
+const DEFAULT_VALIDATOR_FEE_TIMEOUT: DurationSeconds = DurationSeconds::new(7 * 24 * 60 * 60);
+const DEFAULT_VALIDATION_REQUEST_TIMEOUT: DurationSeconds = DurationSeconds::new(3 * 24 * 60 * 60);
+const DEFAULT_PAYMENT_HOLD_TIMEOUT: DurationSeconds = DurationSeconds::new(14 * 24 * 60 * 60);
+const DEFAULT_EXPIRY_GRACE_PERIOD: DurationSeconds = DurationSeconds::new(24 * 60 * 60);
 
 #[multiversx_sc::contract]
 pub trait ValidationRegistry:
@@ -23,15 +35,52 @@ pub trait ValidationRegistry:
     + views::ViewsModule
     + events::EventsModule
     + config::ConfigModule
+    + utils::UtilsModule
+    + validators::ValidatorsModule
+    + common::fees::FeesModule
 {
     #[init]
     fn init(&self, identity_registry_address: ManagedAddress) {
         self.identity_registry_address()
             .set(&identity_registry_address);
+        self.pass_threshold().set(DEFAULT_PASS_THRESHOLD);
+        self.validator_fee_timeout()
+            .set(DEFAULT_VALIDATOR_FEE_TIMEOUT);
+        self.validation_request_timeout()
+            .set(DEFAULT_VALIDATION_REQUEST_TIMEOUT);
+        self.payment_hold_timeout()
+            .set(DEFAULT_PAYMENT_HOLD_TIMEOUT);
+        self.expiry_grace_period().set(DEFAULT_EXPIRY_GRACE_PERIOD);
     }
 
+    /// `agent_nonces`: agents whose validation records predate stored request/response URIs.
+    /// Their records are re-encoded with empty URIs; already migrated records are skipped.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, agent_nonces: MultiValueEncoded<u64>) {
+        for agent_nonce in agent_nonces {
+            self.migrate_validation_requests(agent_nonce);
+        }
+
+        // Deployments from before pass thresholds would otherwise verify any response
+        if self.pass_threshold().is_empty() {
+            self.pass_threshold().set(DEFAULT_PASS_THRESHOLD);
+        }
+        if self.validator_fee_timeout().is_empty() {
+            self.validator_fee_timeout()
+                .set(DEFAULT_VALIDATOR_FEE_TIMEOUT);
+        }
+        if self.validation_request_timeout().is_empty() {
+            self.validation_request_timeout()
+                .set(DEFAULT_VALIDATION_REQUEST_TIMEOUT);
+        }
+        if self.payment_hold_timeout().is_empty() {
+            self.payment_hold_timeout()
+                .set(DEFAULT_PAYMENT_HOLD_TIMEOUT);
+        }
+        if self.expiry_grace_period().is_empty() {
+            self.expiry_grace_period().set(DEFAULT_EXPIRY_GRACE_PERIOD);
+        }
+    }
 
     #[payable("*")]
     #[endpoint(init_job)]
@@ -39,101 +88,168 @@ pub trait ValidationRegistry:
         let job_mapper = self.job_data(&job_id);
         require!(job_mapper.is_empty(), ERR_JOB_ALREADY_INITIALIZED);
 
+        let identity_addr = self.identity_registry_address().get();
+        let agent_owner_mapper = self.external_agent_owner(identity_addr.clone(), agent_nonce);
+        require!(!agent_owner_mapper.is_empty(), ERR_AGENT_NOT_FOUND);
+        require!(
+            self.external_agent_status(identity_addr.clone(), agent_nonce)
+                .get()
+                == AgentStatus::Active,
+            ERR_AGENT_PAUSED
+        );
+
         let caller = self.blockchain().get_caller();
         job_mapper.set(JobData {
             status: JobStatus::New,
             proof: ManagedBuffer::new(),
-            employer: caller,
+            employer: caller.clone(),
             creation_timestamp: self.blockchain().get_block_timestamp_millis(),
             agent_nonce,
         });
 
-        // If service_id provided, validate payment and forward to agent owner
+        // If service_id provided, validate the payments against the tokens the service
+        // accepts and forward them to the agent owner minus the protocol fee, or hold them
+        // until the job is verified if the service asks for that
         if let OptionalValue::Some(sid) = service_id {
-            let identity_addr = self.identity_registry_address().get();
-            let agent_owner = self
-                .external_agents(identity_addr.clone())
-                .get_value(&agent_nonce);
-
-            let service_config_map = self.external_agent_service_config(identity_addr, agent_nonce);
-
-            if let Some(service_payment) = service_config_map.get(&sid) {
-                if let Some(pay) = self.call_value().single_optional() {
-                    require!(
-                        pay.token_identifier == service_payment.token_identifier
-                            && pay.token_nonce == service_payment.token_nonce,
-                        ERR_INVALID_PAYMENT
+            self.job_service_id(&job_id).set(sid);
+            let agent_owner = agent_owner_mapper.get();
+
+            let service_config_map =
+                self.external_agent_service_config(identity_addr.clone(), agent_nonce);
+
+            if let Some(accepted) = service_config_map.get(&sid) {
+                let payments = self.call_value().all().clone();
+                self.require_service_payment(&accepted, &payments);
+
+                let pay_on_verification = self
+                    .external_agent_pay_on_verification_services(identity_addr, agent_nonce)
+                    .contains(&sid);
+                if pay_on_verification {
+                    let deadline = self.blockchain().get_block_timestamp_seconds()
+                        + self.payment_hold_timeout().get();
+                    self.job_held_payment(&job_id).set(HeldPayment {
+                        payments: payments.clone(),
+                        deadline,
+                        protocol_fee_bps: self.protocol_fee_bps().get(),
+                    });
+                    self.payment_held_event(
+                        &job_id,
+                        &caller,
+                        HeldPaymentEventData {
+                            agent_nonce,
+                            payments,
+                            deadline,
+                        },
                     );
-
-                    require!(
-                        pay.amount >= service_payment.amount,
-                        ERR_INSUFFICIENT_PAYMENT
-                    );
-
-                    if pay.amount > 0u64 {
-                        self.tx().to(&agent_owner).payment(pay.clone()).transfer();
-                    }
                 } else {
-                    // No payment sent — only valid if service is free
-                    require!(service_payment.amount == 0u64, ERR_INSUFFICIENT_PAYMENT);
+                    let net_payments =
+                        self.take_protocol_fees(&payments, self.protocol_fee_bps().get());
+                    self.tx().to(&agent_owner).payment(&net_payments).transfer();
                 }
             }
         }
     }
 
+    /// Cancel a job the agent has not started (no proof submitted yet). Only the employer can
+    /// call this. A held service payment is refunded; one already forwarded by `init_job` is
+    /// not. Funds deposited in the escrow contract against this job become refundable right away.
+    #[endpoint(cancel_job)]
+    fn cancel_job(&self, job_id: ManagedBuffer) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+
+        let mut job = job_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(caller == job.employer, ERR_NOT_EMPLOYER);
+
+        self.change_job_status(&job_id, &mut job, JobStatus::Cancelled);
+        job_mapper.set(&job);
+        self.settle_held_payment(&job_id, &job);
+
+        self.job_cancelled_event(
+            &job_id,
+            &caller,
+            JobCancelledEventData {
+                agent_nonce: job.agent_nonce,
+            },
+        );
+    }
+
+    /// Submit (or replace) the proof for a job.
+    /// Caller must be the agent owner or an operator with `PERMISSION_SUBMIT_PROOF`.
     #[endpoint(submit_proof)]
     fn submit_proof(&self, job_id: ManagedBuffer, proof: ManagedBuffer) {
         let job_mapper = self.job_data(&job_id);
         require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
 
-        job_mapper.update(|job| {
-            job.proof = proof;
-            job.status = JobStatus::Pending;
-        });
+        // Caller must be agent owner or an authorised operator
+        let agent_nonce = job_mapper.get().agent_nonce;
+        self.require_agent_owner_or_operator(agent_nonce, PERMISSION_SUBMIT_PROOF);
+
+        self.store_proof(&job_id, proof);
     }
 
-    /// NFT-holder variant: proves ownership by sending the agent NFT.
-    /// The contract verifies token ID + nonce, executes proof, and returns the NFT.
-    #[payable("*")]
-    #[endpoint(submit_proof_with_nft)]
-    fn submit_proof_with_nft(&self, job_id: ManagedBuffer, proof: ManagedBuffer) {
+    /// Relayed proof submission: anyone may submit `proof` on the agent's behalf, as long as
+    /// `signature` is an Ed25519 signature over `signed_proof_message(job_id, proof)` by the
+    /// agent's registered `public_key`. Lets agents operate without an on-chain wallet.
+    /// The message covers the job's proof nonce, so a signature stops verifying once any
+    /// proof has been stored after it was made.
+    #[endpoint(submit_signed_proof)]
+    fn submit_signed_proof(
+        &self,
+        job_id: ManagedBuffer,
+        proof: ManagedBuffer,
+        signature: ManagedBuffer,
+    ) {
         let job_mapper = self.job_data(&job_id);
         require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+        let agent_nonce = job_mapper.get().agent_nonce;
 
-        let payment = self.call_value().single_esdt();
-        let job_data = job_mapper.get();
-
-        // Read agent token ID from identity-registry
         let identity_addr = self.identity_registry_address().get();
-        let expected_token_id = self.external_agent_token_id(identity_addr).get();
-        require!(
-            payment.token_identifier == expected_token_id,
-            ERR_INVALID_AGENT_NFT
-        );
+        let details_mapper = self.external_agent_details(identity_addr, agent_nonce);
+        require!(!details_mapper.is_empty(), ERR_AGENT_NOT_FOUND);
+        let public_key = details_mapper.get().public_key;
         require!(
-            payment.token_nonce == job_data.agent_nonce,
-            ERR_INVALID_AGENT_NFT
+            public_key.len() == ED25519_PUBLIC_KEY_LENGTH,
+            ERR_INVALID_PUBLIC_KEY
         );
 
-        job_mapper.update(|job| {
-            job.proof = proof;
-            job.status = JobStatus::Pending;
-        });
+        // Fails the transaction on an invalid signature
+        let message = self.signed_proof_message(&job_id, &proof);
+        self.crypto()
+            .verify_ed25519(&public_key, &message, &signature);
 
-        // Return NFT to caller
-        let caller = self.blockchain().get_caller();
-        self.tx()
-            .to(&caller)
-            .single_esdt(
-                &payment.token_identifier,
-                payment.token_nonce,
-                &payment.amount,
-            )
-            .transfer();
+        self.store_proof(&job_id, proof);
+    }
+
+    /// Split a job into deliverables validated one after the other, for milestone escrows.
+    /// Only the employer can call this, before the first proof. Afterwards a request that
+    /// reaches its quorum only records whether its deliverable passed; the job returns to
+    /// `Pending` for the next proof and is never verified or rejected.
+    #[endpoint(enable_job_milestones)]
+    fn enable_job_milestones(&self, job_id: ManagedBuffer) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+
+        let job = job_mapper.get();
+        require!(
+            self.blockchain().get_caller() == job.employer,
+            ERR_NOT_EMPLOYER
+        );
+        require!(job.status == JobStatus::New, ERR_JOB_ALREADY_STARTED);
+        require!(
+            self.job_held_payment(&job_id).is_empty(),
+            ERR_MILESTONES_WITH_HELD_PAYMENT
+        );
+        self.job_milestones(&job_id).set(true);
     }
 
     /// ERC-8004: Agent requests validation from a specific validator.
-    /// MUST be called by the owner of the agent (agentId).
+    /// MUST be called by the owner of the agent (agentId) or an operator with `PERMISSION_REQUEST_VALIDATION`,
+    /// neither of whom can be the validator. An optional payment is escrowed as the validator's fee, paid out on its first response.
+    /// `request_hash` must not have been used before. When `request_content` (the document
+    /// behind `request_uri`) is supplied, `request_hash` must be its keccak256.
+    #[payable("*")]
     #[endpoint(validation_request)]
     fn validation_request(
         &self,
@@ -141,93 +257,311 @@ pub trait ValidationRegistry:
         validator_address: ManagedAddress,
         request_uri: ManagedBuffer,
         request_hash: ManagedBuffer,
+        request_content: OptionalValue<ManagedBuffer>,
     ) {
-        let job_mapper = self.job_data(&job_id);
-        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
-
-        let job_data = job_mapper.get();
+        if let OptionalValue::Some(content) = request_content {
+            require!(
+                self.crypto().keccak256(&content).as_managed_buffer() == &request_hash,
+                ERR_REQUEST_HASH_MISMATCH
+            );
+        }
 
-        // Caller must be agent owner
-        let caller = self.blockchain().get_caller();
-        let identity_addr = self.identity_registry_address().get();
-        let agent_owner = self
-            .external_agents(identity_addr)
-            .get_value(&job_data.agent_nonce);
-        require!(caller == agent_owner, ERR_NOT_AGENT_OWNER);
-
-        // Store validation request
-        let request_data = ValidationRequestData {
-            validator_address: validator_address.clone(),
-            agent_nonce: job_data.agent_nonce,
-            job_id: job_id.clone(),
-            response: 0,
-            response_hash: ManagedBuffer::new(),
-            tag: ManagedBuffer::new(),
-            last_update: TimestampSeconds::new(0),
-        };
-
-        self.validation_request_data(&request_hash)
-            .set(&request_data);
-        self.agent_validations(job_data.agent_nonce)
-            .insert(request_hash.clone());
-
-        // Update job status
-        job_mapper.update(|job| {
-            job.status = JobStatus::ValidationRequested;
-        });
+        let mut validators = ManagedVec::new();
+        validators.push(validator_address);
+        self.open_validation_request(job_id, validators, 1, request_uri, request_hash);
+    }
 
-        self.validation_request_event(
-            validator_address,
-            job_data.agent_nonce,
+    /// Request validation from several validators at once. The job outcome is decided once
+    /// `quorum` of them have responded, using the median of their responses.
+    /// Same access rules as `validation_request`; an attached fee is split evenly between the validators.
+    #[payable("*")]
+    #[endpoint(quorum_validation_request)]
+    fn quorum_validation_request(
+        &self,
+        job_id: ManagedBuffer,
+        request_uri: ManagedBuffer,
+        request_hash: ManagedBuffer,
+        quorum: u32,
+        validators: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.open_validation_request(
+            job_id,
+            validators.to_vec(),
+            quorum,
             request_uri,
             request_hash,
         );
     }
 
     /// ERC-8004: Validator responds with a result (0-100).
-    /// MUST be called by the validatorAddress from the original request.
-    /// Can be called multiple times for progressive validation.
+    /// MUST be called by a validator nominated in the original request; each validator's
+    /// latest response is kept, and `response_uri` is stored on the request as the latest
+    /// evidence link. Once `quorum` validators have responded, the job is decided:
+    /// `Verified` if the median response is at or above the job's pass threshold, `Rejected`
+    /// below it. A held service payment then goes to the agent owner or back to the employer.
+    /// Later (progressive) responses update the record only.
     #[endpoint(validation_response)]
     fn validation_response(
         &self,
         request_hash: ManagedBuffer,
         response: u8,
-        _response_uri: ManagedBuffer,
+        response_uri: ManagedBuffer,
         response_hash: ManagedBuffer,
         tag: ManagedBuffer,
     ) {
         let request_mapper = self.validation_request_data(&request_hash);
         require!(!request_mapper.is_empty(), ERR_VALIDATION_REQUEST_NOT_FOUND);
+        require!(response <= MAX_VALIDATION_SCORE, ERR_INVALID_RESPONSE);
+
+        require!(
+            !self.request_expired(&request_hash).get(),
+            ERR_REQUEST_EXPIRED
+        );
 
         let caller = self.blockchain().get_caller();
+        let mut request_data = request_mapper.get();
+        require!(
+            self.is_nominated_validator(&request_hash, &request_data, &caller),
+            ERR_NOT_VALIDATOR
+        );
 
-        request_mapper.update(|data| {
-            require!(caller == data.validator_address, ERR_NOT_VALIDATOR);
+        let last_update = self.blockchain().get_block_timestamp_seconds();
+        let mut responses = self.validator_responses(&request_hash);
+        if !responses.contains_key(&caller) {
+            self.pay_validator_fee(&request_hash, &caller);
+        }
+        responses.insert(
+            caller.clone(),
+            ValidatorResponse {
+                response,
+                response_hash: response_hash.clone(),
+                tag: tag.clone(),
+                last_update,
+            },
+        );
 
-            data.response = response;
-            data.response_hash = response_hash;
-            data.tag = tag;
-            data.last_update = self.blockchain().get_block_timestamp_seconds();
-        });
+        request_data.response = self.aggregate_response(&request_hash);
+        request_data.response_hash = response_hash;
+        request_data.response_uri = response_uri;
+        request_data.tag = tag;
+        request_data.last_update = last_update;
+        request_mapper.set(&request_data);
+
+        // Decide the job outcome against its pass threshold once the quorum is reached
+        let quorum_reached =
+            responses.len() >= self.request_quorum_or_default(&request_hash) as usize;
+        if quorum_reached {
+            self.job_open_requests(&request_data.job_id)
+                .swap_remove(&request_hash);
+        }
+        let job_mapper = self.job_data(&request_data.job_id);
+        if quorum_reached && !job_mapper.is_empty() {
+            let mut job = job_mapper.get();
+            let passed_mapper = self.request_passed(&request_hash);
+            if passed_mapper.is_empty() {
+                let threshold = self.job_pass_threshold(&request_data.job_id, job.agent_nonce);
+                passed_mapper.set(request_data.response >= threshold);
+            }
+            if self.job_milestones(&request_data.job_id).get() {
+                // One deliverable decided; the job stays open for the next one
+                if job.status == JobStatus::ValidationRequested
+                    && self.job_open_requests(&request_data.job_id).is_empty()
+                {
+                    self.change_job_status(&request_data.job_id, &mut job, JobStatus::Pending);
+                    job_mapper.set(&job);
+                }
+            } else if job.status == JobStatus::ValidationRequested {
+                let outcome = if passed_mapper.get() {
+                    JobStatus::Verified
+                } else {
+                    JobStatus::Rejected
+                };
+                self.change_job_status(&request_data.job_id, &mut job, outcome);
+                job_mapper.set(&job);
+                self.settle_held_payment(&request_data.job_id, &job);
+            }
+        }
 
-        let updated_data = request_mapper.get();
+        self.validation_response_event(
+            &caller,
+            request_data.agent_nonce,
+            &request_hash,
+            ValidationResponseEventData {
+                job_id: request_data.job_id,
+                response,
+                response_uri: request_data.response_uri,
+                response_hash: request_data.response_hash,
+                tag: request_data.tag,
+                aggregate_response: request_data.response,
+                quorum_reached,
+            },
+        );
+    }
 
-        // Transition job status to Verified
-        let job_mapper = self.job_data(&updated_data.job_id);
-        if !job_mapper.is_empty() {
-            job_mapper.update(|job| {
-                job.status = JobStatus::Verified;
+    /// Close a request whose deadline passed without reaching its quorum. Anyone can call this.
+    /// If it was the job's last open request, the job rolls back to `Pending` so the agent can
+    /// request validation again (and may replace the proof).
+    #[endpoint(expire_validation_request)]
+    fn expire_validation_request(&self, request_hash: ManagedBuffer) {
+        let request_data = self.require_open_request(&request_hash);
+        let now = self.blockchain().get_block_timestamp_seconds();
+        let deadline = self.request_deadline(&request_hash).get();
+        require!(now > deadline, ERR_REQUEST_DEADLINE_NOT_PASSED);
+        // The agent gets the grace period to renominate before anyone can close the request
+        require!(
+            now > deadline + self.expiry_grace_period().get(),
+            ERR_EXPIRY_GRACE_NOT_PASSED
+        );
+
+        self.request_expired(&request_hash).set(true);
+        let job_id = request_data.job_id;
+        let mut open_requests = self.job_open_requests(&job_id);
+        open_requests.swap_remove(&request_hash);
+
+        let job_mapper = self.job_data(&job_id);
+        if open_requests.is_empty() && !job_mapper.is_empty() {
+            let mut job = job_mapper.get();
+            if job.status == JobStatus::ValidationRequested {
+                self.change_job_status(&job_id, &mut job, JobStatus::Pending);
+                job_mapper.set(&job);
+            }
+        }
+
+        self.validation_request_expired_event(
+            &request_hash,
+            &job_id,
+            ValidationRequestExpiredEventData {
+                quorum: self.request_quorum_or_default(&request_hash),
+                response_count: self.validator_responses(&request_hash).len() as u32,
+            },
+        );
+    }
+
+    /// Replace a nominated validator that let the deadline pass without responding.
+    /// The request gets a fresh deadline and the new validator takes over the fee share.
+    /// Same access rules as `validation_request`, including who can be nominated.
+    #[endpoint(renominate_validator)]
+    fn renominate_validator(
+        &self,
+        request_hash: ManagedBuffer,
+        previous_validator: ManagedAddress,
+        new_validator: ManagedAddress,
+    ) {
+        let mut request_data = self.require_open_request(&request_hash);
+        self.require_agent_owner_or_operator(
+            request_data.agent_nonce,
+            PERMISSION_REQUEST_VALIDATION,
+        );
+        let now = self.blockchain().get_block_timestamp_seconds();
+        let deadline_mapper = self.request_deadline(&request_hash);
+        require!(now > deadline_mapper.get(), ERR_REQUEST_DEADLINE_NOT_PASSED);
+        require!(
+            self.is_nominated_validator(&request_hash, &request_data, &previous_validator),
+            ERR_NOT_VALIDATOR
+        );
+        require!(
+            !self
+                .validator_responses(&request_hash)
+                .contains_key(&previous_validator),
+            ERR_VALIDATOR_ALREADY_RESPONDED
+        );
+        require!(
+            !self.is_nominated_validator(&request_hash, &request_data, &new_validator),
+            ERR_DUPLICATE_VALIDATOR
+        );
+        require!(
+            !self.require_staked_validators().get() || self.is_eligible_validator(&new_validator),
+            ERR_VALIDATOR_NOT_ELIGIBLE
+        );
+        self.require_independent_validator(request_data.agent_nonce, &new_validator);
+
+        let mut nominated = self.request_validators(&request_hash);
+        if !nominated.is_empty() {
+            nominated.swap_remove(&previous_validator);
+            nominated.insert(new_validator.clone());
+        }
+        if request_data.validator_address == previous_validator {
+            request_data.validator_address = new_validator.clone();
+            self.validation_request_data(&request_hash)
+                .set(&request_data);
+        }
+
+        let deadline = now + self.validation_request_timeout().get();
+        deadline_mapper.set(deadline);
+        let fee_mapper = self.validation_fee(&request_hash);
+        if !fee_mapper.is_empty() {
+            fee_mapper.update(|fee| {
+                if fee.deadline < deadline {
+                    fee.deadline = deadline;
+                }
             });
         }
 
-        self.validation_response_event(
-            caller,
-            updated_data.agent_nonce,
-            request_hash,
-            updated_data,
+        self.validator_renominated_event(
+            &request_hash,
+            &previous_validator,
+            &new_validator,
+            ValidatorRenominatedEventData {
+                job_id: request_data.job_id,
+                deadline,
+            },
+        );
+    }
+
+    /// Return the unpaid part of a request's validator fee to whoever paid it, once the
+    /// fee timeout has passed. Anyone can call this.
+    #[endpoint(refund_validation_fee)]
+    fn refund_validation_fee(&self, request_hash: ManagedBuffer) {
+        let fee_mapper = self.validation_fee(&request_hash);
+        require!(!fee_mapper.is_empty(), ERR_FEE_NOT_FOUND);
+
+        let fee = fee_mapper.get();
+        require!(
+            self.blockchain().get_block_timestamp_seconds() > fee.deadline,
+            ERR_FEE_TIMEOUT_NOT_PASSED
         );
+
+        // Effects before interactions
+        fee_mapper.clear();
+
+        self.tx()
+            .to(&fee.payer)
+            .egld_or_single_esdt(&fee.token_identifier, fee.token_nonce, &fee.remaining)
+            .transfer();
+
+        self.validator_fee_refunded_event(
+            &request_hash,
+            &fee.payer,
+            ValidatorFeeEventData {
+                token_identifier: fee.token_identifier,
+                token_nonce: fee.token_nonce,
+                amount: fee.remaining,
+            },
+        );
+    }
+
+    /// Refund a held service payment to the employer once the hold has run out without the
+    /// job being verified. Anyone can call this. The job is closed as `Expired`, so a late
+    /// passing response can no longer verify it.
+    #[endpoint(refund_held_payment)]
+    fn refund_held_payment(&self, job_id: ManagedBuffer) {
+        let held_mapper = self.job_held_payment(&job_id);
+        require!(!held_mapper.is_empty(), ERR_NO_HELD_PAYMENT);
+        require!(
+            self.blockchain().get_block_timestamp_seconds() > held_mapper.get().deadline,
+            ERR_PAYMENT_HOLD_NOT_EXPIRED
+        );
+
+        let job_mapper = self.job_data(&job_id);
+        let mut job = job_mapper.get();
+        self.change_job_status(&job_id, &mut job, JobStatus::Expired);
+        job_mapper.set(&job);
+        self.settle_held_payment(&job_id, &job);
     }
 
+    /// Remove jobs older than 3 days. Anyone can call this. Jobs still under way or backing
+    /// funds are skipped. Request records and the validation history stay: escrows read
+    /// them to release milestones, and request hashes are never reused.
     #[endpoint(clean_old_jobs)]
     fn clean_old_jobs(&self, job_ids: MultiValueEncoded<ManagedBuffer>) {
         let current_time = self.blockchain().get_block_timestamp_millis();
@@ -237,8 +571,17 @@ pub trait ValidationRegistry:
                 continue;
             }
             let job_data = job_mapper.get();
-            if current_time > job_data.creation_timestamp + THREE_DAYS {
+            // Jobs still holding a service payment, an open request or escrowed funds must
+            // be settled first; verified and milestone jobs stay as proof for the escrow contract
+            if current_time > job_data.creation_timestamp + THREE_DAYS
+                && job_data.status != JobStatus::Verified
+                && !self.job_milestones(&job_id).get()
+                && self.job_held_payment(&job_id).is_empty()
+                && self.job_open_requests(&job_id).is_empty()
+                && !self.has_active_escrow(&job_id)
+            {
                 job_mapper.clear();
+                self.job_service_id(&job_id).clear();
             }
         }
     }
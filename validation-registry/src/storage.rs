@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 
-use crate::structs::{JobData, ValidationRequestData};
+use crate::structs::{
+    HeldPayment, JobData, ValidationFee, ValidationRequestData, ValidatorInfo, ValidatorResponse,
+};
 
 #[multiversx_sc::module]
 pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
@@ -9,9 +11,40 @@ pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
     #[storage_mapper("jobData")]
     fn job_data(&self, job_id: &ManagedBuffer) -> SingleValueMapper<JobData<Self::Api>>;
 
+    /// Service the job was opened for via `init_job`; empty for jobs without a service.
+    #[storage_mapper("jobServiceId")]
+    fn job_service_id(&self, job_id: &ManagedBuffer) -> SingleValueMapper<u32>;
+
+    /// Set by `enable_job_milestones`: each validation request decides one deliverable
+    /// (see `requestPassed`) and the job goes back to `Pending` instead of being finalised.
+    #[view(is_milestone_job)]
+    #[storage_mapper("jobMilestones")]
+    fn job_milestones(&self, job_id: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    /// Service payment held for a pay-on-verification job until it is decided.
+    #[storage_mapper("jobHeldPayment")]
+    fn job_held_payment(&self, job_id: &ManagedBuffer)
+    -> SingleValueMapper<HeldPayment<Self::Api>>;
+
+    /// Proofs stored so far for the job, signed into `submit_signed_proof` messages so an
+    /// earlier signature cannot be replayed. Kept when the job is cleaned.
+    #[view(get_proof_nonce)]
+    #[storage_mapper("jobProofNonce")]
+    fn job_proof_nonce(&self, job_id: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[view(get_payment_hold_timeout)]
+    #[storage_mapper("paymentHoldTimeout")]
+    fn payment_hold_timeout(&self) -> SingleValueMapper<DurationSeconds>;
+
     #[storage_mapper("identityRegistryAddress")]
     fn identity_registry_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Escrow contract whose active escrows keep their jobs from being cleaned. Unset
+    /// until the owner configures it.
+    #[view(get_escrow_contract_address)]
+    #[storage_mapper("escrowContractAddress")]
+    fn escrow_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
     // ── ERC-8004 Validation storage ──
 
     #[storage_mapper("validationRequestData")]
@@ -20,6 +53,114 @@ pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
         request_hash: &ManagedBuffer,
     ) -> SingleValueMapper<ValidationRequestData<Self::Api>>;
 
+    /// Raw bytes of `validationRequestData`, so `upgrade` can tell legacy records apart.
+    #[storage_mapper("validationRequestData")]
+    fn raw_validation_request_data(
+        &self,
+        request_hash: &ManagedBuffer,
+    ) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Minimum response for a job to be `Verified`; lower responses reject it.
+    #[view(get_pass_threshold)]
+    #[storage_mapper("passThreshold")]
+    fn pass_threshold(&self) -> SingleValueMapper<u8>;
+
+    /// Validators nominated for a request. Empty for requests opened before quorums, whose
+    /// only validator is `ValidationRequestData::validator_address`.
+    #[storage_mapper("requestValidators")]
+    fn request_validators(
+        &self,
+        request_hash: &ManagedBuffer,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Responses needed before the job outcome is decided. Empty reads as 1.
+    #[storage_mapper("requestQuorum")]
+    fn request_quorum(&self, request_hash: &ManagedBuffer) -> SingleValueMapper<u32>;
+
+    /// After this, an unanswered request can be expired or its silent validators replaced.
+    /// Empty (zero) for requests opened before deadlines existed.
+    #[storage_mapper("requestDeadline")]
+    fn request_deadline(&self, request_hash: &ManagedBuffer)
+    -> SingleValueMapper<TimestampSeconds>;
+
+    #[storage_mapper("requestExpired")]
+    fn request_expired(&self, request_hash: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    /// Decided once, when the request first reaches its quorum: whether its aggregate response
+    /// met the job's pass threshold. Empty while undecided. Read by escrow for milestones.
+    #[view(is_request_passed)]
+    #[storage_mapper("requestPassed")]
+    fn request_passed(&self, request_hash: &ManagedBuffer) -> SingleValueMapper<bool>;
+
+    /// Every request hash opened on a job id, oldest first. Append-only: kept when the job is
+    /// cleaned, like the request records it points to, as an audit trail of the id.
+    #[storage_mapper("jobValidationHistory")]
+    fn job_validation_history(&self, job_id: &ManagedBuffer) -> VecMapper<ManagedBuffer>;
+
+    /// Requests on a job still waiting for their quorum.
+    #[storage_mapper("jobOpenRequests")]
+    fn job_open_requests(&self, job_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(get_validation_request_timeout)]
+    #[storage_mapper("validationRequestTimeout")]
+    fn validation_request_timeout(&self) -> SingleValueMapper<DurationSeconds>;
+
+    /// Time after a request's deadline reserved for `renominate_validator`; only then can
+    /// anyone expire the request.
+    #[view(get_expiry_grace_period)]
+    #[storage_mapper("expiryGracePeriod")]
+    fn expiry_grace_period(&self) -> SingleValueMapper<DurationSeconds>;
+
+    #[storage_mapper("validatorResponses")]
+    fn validator_responses(
+        &self,
+        request_hash: &ManagedBuffer,
+    ) -> MapMapper<ManagedAddress, ValidatorResponse<Self::Api>>;
+
     #[storage_mapper("agentValidations")]
     fn agent_validations(&self, agent_nonce: u64) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // ── Validator fees ──
+
+    #[storage_mapper("validationFee")]
+    fn validation_fee(
+        &self,
+        request_hash: &ManagedBuffer,
+    ) -> SingleValueMapper<ValidationFee<Self::Api>>;
+
+    /// How long validators have to answer before an escrowed fee becomes refundable.
+    #[view(get_validator_fee_timeout)]
+    #[storage_mapper("validatorFeeTimeout")]
+    fn validator_fee_timeout(&self) -> SingleValueMapper<DurationSeconds>;
+
+    // ── Validator registry ──
+
+    #[storage_mapper("validatorInfo")]
+    fn validator_info(
+        &self,
+        validator: &ManagedAddress,
+    ) -> SingleValueMapper<ValidatorInfo<Self::Api>>;
+
+    #[storage_mapper("registeredValidators")]
+    fn registered_validators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("tagValidators")]
+    fn tag_validators(&self, tag: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(get_validator_stake_token)]
+    #[storage_mapper("validatorStakeToken")]
+    fn validator_stake_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(get_min_validator_stake)]
+    #[storage_mapper("minValidatorStake")]
+    fn min_validator_stake(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(get_validator_unbonding_period)]
+    #[storage_mapper("validatorUnbondingPeriod")]
+    fn validator_unbonding_period(&self) -> SingleValueMapper<DurationSeconds>;
+
+    /// When set, only active validators staked at least `minValidatorStake` can be nominated.
+    #[view(is_staked_validator_required)]
+    #[storage_mapper("requireStakedValidators")]
+    fn require_staked_validators(&self) -> SingleValueMapper<bool>;
 }
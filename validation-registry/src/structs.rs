@@ -1 +1,88 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
 pub use common::structs::*;
+
+/// `ValidationRequestData` layout from before the request and response URIs were stored.
+/// Only decoded by the `upgrade` migration.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LegacyValidationRequestData<M: ManagedTypeApi> {
+    pub validator_address: ManagedAddress<M>,
+    pub agent_nonce: u64,
+    pub job_id: ManagedBuffer<M>,
+    pub response: u8,
+    pub response_hash: ManagedBuffer<M>,
+    pub tag: ManagedBuffer<M>,
+    pub last_update: TimestampSeconds,
+}
+
+/// Service payments kept by the contract until the job is decided. Refundable to the
+/// employer after `deadline` if the job has not been verified by then. A release pays
+/// the protocol fee at `protocol_fee_bps`, the rate when the job was opened.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct HeldPayment<M: ManagedTypeApi> {
+    pub payments: ManagedVec<M, Payment<M>>,
+    pub deadline: TimestampSeconds,
+    pub protocol_fee_bps: u32,
+}
+
+/// One nominated validator's latest answer to a validation request.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ValidatorResponse<M: ManagedTypeApi> {
+    pub response: u8,
+    pub response_hash: ManagedBuffer<M>,
+    pub tag: ManagedBuffer<M>,
+    pub last_update: TimestampSeconds,
+}
+
+/// Quorum progress of a validation request. `aggregate_response` is the median of the
+/// responses received so far (0 while none have arrived).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ValidationOutcome {
+    pub quorum: u32,
+    pub validator_count: u32,
+    pub response_count: u32,
+    pub aggregate_response: u8,
+    pub quorum_reached: bool,
+    pub deadline: TimestampSeconds,
+    pub expired: bool,
+}
+
+/// Fee escrowed with a validation request. Each nominated validator is paid
+/// `fee_per_validator` on its first response before `deadline`; whatever is left
+/// afterwards (including rounding dust) is refundable to `payer`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ValidationFee<M: ManagedTypeApi> {
+    pub payer: ManagedAddress<M>,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub fee_per_validator: BigUint<M>,
+    pub remaining: BigUint<M>,
+    pub deadline: TimestampSeconds,
+}
+
+/// `Unbonding` validators can no longer be nominated and withdraw their stake after the
+/// unbonding period, which keeps them slashable for responses given shortly before leaving.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ValidatorStatus {
+    Active,
+    Unbonding,
+}
+
+/// A registered validator. The stake keeps the token it was bonded in, so changing the
+/// staking configuration never strands existing stakes.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ValidatorInfo<M: ManagedTypeApi> {
+    pub stake_token: EgldOrEsdtTokenIdentifier<M>,
+    pub stake_nonce: u64,
+    pub stake: BigUint<M>,
+    pub tags: ManagedVec<M, ManagedBuffer<M>>,
+    pub status: ValidatorStatus,
+    pub unbond_timestamp: TimestampSeconds,
+}
@@ -0,0 +1,559 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::events::{
+    HeldPaymentEventData, JobStatusChangedEventData, ValidationRequestEventData,
+    ValidatorFeeEventData,
+};
+use crate::structs::{
+    EscrowStatus, JobData, JobStatus, LegacyValidationRequestData, MAX_VALIDATION_SCORE,
+    PERMISSION_REQUEST_VALIDATION, ValidationFee, ValidationRequestData, ValidatorResponse,
+};
+
+/// Upper bound on nominated validators per request, keeping aggregation gas bounded.
+pub const MAX_VALIDATORS_PER_REQUEST: usize = 20;
+
+/// Job state machine. Self-transitions cover proof re-submission, additional validation
+/// requests and progressive responses; they are allowed but do not emit `jobStatusChanged`.
+///
+/// ```text
+/// New                 -> Pending | Cancelled | Expired
+/// Pending             -> Pending | ValidationRequested | Expired
+/// ValidationRequested -> ValidationRequested | Pending | Verified | Rejected | Expired
+/// Verified            -> Verified
+/// Rejected            -> Rejected
+/// ```
+pub fn is_allowed_transition(from: JobStatus, to: JobStatus) -> bool {
+    use JobStatus::*;
+    matches!(
+        (from, to),
+        (New, Pending | Cancelled | Expired)
+            | (Pending, Pending | ValidationRequested | Expired)
+            | (
+                ValidationRequested,
+                ValidationRequested | Pending | Verified | Rejected | Expired
+            )
+            | (Verified, Verified)
+            | (Rejected, Rejected)
+    )
+}
+
+/// Median of validator scores; the mean of the two middle scores (rounded down) for an
+/// even count, 0 when there are none. Scores are bucketed, so no sorting is needed.
+pub fn median_score<I: Iterator<Item = u8>>(scores: I) -> u8 {
+    let mut histogram = [0u32; MAX_VALIDATION_SCORE as usize + 1];
+    let mut count = 0u32;
+    for score in scores {
+        histogram[score as usize] += 1;
+        count += 1;
+    }
+    if count == 0 {
+        return 0;
+    }
+
+    let lower_rank = (count - 1) / 2;
+    let upper_rank = count / 2;
+    let mut lower = None;
+    let mut seen = 0u32;
+    for (score, &bucket) in histogram.iter().enumerate() {
+        seen += bucket;
+        if lower.is_none() && seen > lower_rank {
+            lower = Some(score as u32);
+        }
+        if seen > upper_rank {
+            return ((lower.unwrap_or(score as u32) + score as u32) / 2) as u8;
+        }
+    }
+    0
+}
+
+#[multiversx_sc::module]
+pub trait UtilsModule:
+    common::cross_contract::CrossContractModule
+    + crate::storage::ExternalStorageModule
+    + crate::events::EventsModule
+    + crate::validators::ValidatorsModule
+    + common::fees::FeesModule
+{
+    /// Caller must own the agent in identity-registry, or be an operator holding every bit of `permission`.
+    fn require_agent_owner_or_operator(&self, agent_nonce: u64, permission: u8) -> ManagedAddress {
+        let identity_addr = self.identity_registry_address().get();
+        let owner_mapper = self.external_agent_owner(identity_addr.clone(), agent_nonce);
+        require!(!owner_mapper.is_empty(), ERR_AGENT_NOT_FOUND);
+
+        let caller = self.blockchain().get_caller();
+        if caller != owner_mapper.get() {
+            let granted = self
+                .external_agent_operators(identity_addr, agent_nonce)
+                .get(&caller)
+                .unwrap_or_default();
+            require!(granted & permission == permission, ERR_NOT_AGENT_OWNER);
+        }
+        caller
+    }
+
+    /// The agent's owner and operators cannot validate its work: a self-verified job would
+    /// release the employer's escrow to them.
+    fn require_independent_validator(&self, agent_nonce: u64, validator: &ManagedAddress) {
+        let identity_addr = self.identity_registry_address().get();
+        let owner_mapper = self.external_agent_owner(identity_addr.clone(), agent_nonce);
+        require!(
+            owner_mapper.is_empty() || owner_mapper.get() != *validator,
+            ERR_VALIDATOR_IS_AGENT
+        );
+        require!(
+            !self
+                .external_agent_operators(identity_addr, agent_nonce)
+                .contains_key(validator),
+            ERR_VALIDATOR_IS_AGENT
+        );
+    }
+
+    /// Move `job` to `new_status`, rejecting transitions outside the state machine with a
+    /// dedicated error. Emits `jobStatusChanged` when the status actually changes.
+    /// The caller is responsible for persisting `job`.
+    fn change_job_status(
+        &self,
+        job_id: &ManagedBuffer,
+        job: &mut JobData<Self::Api>,
+        new_status: JobStatus,
+    ) {
+        let previous_status = job.status;
+        if !is_allowed_transition(previous_status, new_status) {
+            sc_panic!(self.transition_error(previous_status, new_status));
+        }
+        if previous_status == new_status {
+            return;
+        }
+
+        job.status = new_status;
+        self.job_status_changed_event(
+            job_id,
+            previous_status,
+            new_status,
+            JobStatusChangedEventData {
+                agent_nonce: job.agent_nonce,
+                timestamp: self.blockchain().get_block_timestamp_seconds(),
+            },
+        );
+    }
+
+    fn transition_error(&self, from: JobStatus, to: JobStatus) -> &'static str {
+        match (from, to) {
+            (JobStatus::Verified | JobStatus::Rejected, JobStatus::Pending) => ERR_PROOF_LOCKED,
+            (JobStatus::New, JobStatus::ValidationRequested) => ERR_PROOF_REQUIRED,
+            (JobStatus::New | JobStatus::Pending, JobStatus::Verified | JobStatus::Rejected) => {
+                ERR_VALIDATION_NOT_REQUESTED
+            }
+            (
+                JobStatus::Verified
+                | JobStatus::Rejected
+                | JobStatus::Cancelled
+                | JobStatus::Expired,
+                _,
+            ) => ERR_JOB_FINALIZED,
+            (_, JobStatus::Cancelled) => ERR_JOB_NOT_CANCELLABLE,
+            _ => ERR_INVALID_STATUS_TRANSITION,
+        }
+    }
+
+    /// Open a validation request on `job_id`, nominating `validators` of which `quorum`
+    /// must respond before the job outcome is decided. Caller must be the agent owner or an
+    /// operator with `PERMISSION_REQUEST_VALIDATION`, and none of them may be nominated.
+    /// Any attached payment is escrowed as the validators' fee. Each `request_hash` can only
+    /// be used once.
+    fn open_validation_request(
+        &self,
+        job_id: ManagedBuffer,
+        validators: ManagedVec<ManagedAddress>,
+        quorum: u32,
+        request_uri: ManagedBuffer,
+        request_hash: ManagedBuffer,
+    ) {
+        let job_mapper = self.job_data(&job_id);
+        require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
+        require!(
+            self.validation_request_data(&request_hash).is_empty(),
+            ERR_DUPLICATE_REQUEST_HASH
+        );
+        require!(
+            validators.len() <= MAX_VALIDATORS_PER_REQUEST,
+            ERR_TOO_MANY_VALIDATORS
+        );
+        require!(
+            quorum >= 1 && quorum as usize <= validators.len(),
+            ERR_INVALID_QUORUM
+        );
+
+        let mut job_data = job_mapper.get();
+        let caller = self
+            .require_agent_owner_or_operator(job_data.agent_nonce, PERMISSION_REQUEST_VALIDATION);
+        self.change_job_status(&job_id, &mut job_data, JobStatus::ValidationRequested);
+
+        let staked_only = self.require_staked_validators().get();
+        let mut nominated = self.request_validators(&request_hash);
+        for validator in validators.iter() {
+            require!(
+                !staked_only || self.is_eligible_validator(&validator),
+                ERR_VALIDATOR_NOT_ELIGIBLE
+            );
+            self.require_independent_validator(job_data.agent_nonce, &validator);
+            require!(
+                nominated.insert(validator.clone_value()),
+                ERR_DUPLICATE_VALIDATOR
+            );
+        }
+        let deadline = self.blockchain().get_block_timestamp_seconds()
+            + self.validation_request_timeout().get();
+        self.request_quorum(&request_hash).set(quorum);
+        self.request_deadline(&request_hash).set(deadline);
+        self.request_expired(&request_hash).clear();
+        self.job_open_requests(&job_id).insert(request_hash.clone());
+        self.escrow_validator_fee(&request_hash, &caller, validators.len());
+
+        // Store validation request; `validator_address` holds the first nominee
+        let request_data = ValidationRequestData {
+            validator_address: validators.get(0).clone_value(),
+            agent_nonce: job_data.agent_nonce,
+            job_id: job_id.clone(),
+            response: 0,
+            response_hash: ManagedBuffer::new(),
+            tag: ManagedBuffer::new(),
+            last_update: TimestampSeconds::new(0),
+            request_uri: request_uri.clone(),
+            response_uri: ManagedBuffer::new(),
+        };
+
+        self.validation_request_data(&request_hash)
+            .set(&request_data);
+        self.agent_validations(job_data.agent_nonce)
+            .insert(request_hash.clone());
+        self.job_validation_history(&job_id).push(&request_hash);
+
+        job_mapper.set(&job_data);
+
+        for validator in validators.iter() {
+            self.validation_request_event(
+                &validator,
+                job_data.agent_nonce,
+                &request_hash,
+                ValidationRequestEventData {
+                    job_id: job_id.clone(),
+                    request_uri: request_uri.clone(),
+                    quorum,
+                    deadline,
+                },
+            );
+        }
+    }
+
+    /// Escrow the call's payment (if any) as the fee for `request_hash`, split evenly
+    /// between its `validator_count` nominees.
+    fn escrow_validator_fee(
+        &self,
+        request_hash: &ManagedBuffer,
+        payer: &ManagedAddress,
+        validator_count: usize,
+    ) {
+        let payment = self.call_value().egld_or_single_esdt();
+        if payment.amount == 0u64 {
+            return;
+        }
+
+        let fee_mapper = self.validation_fee(request_hash);
+        require!(fee_mapper.is_empty(), ERR_FEE_ALREADY_ESCROWED);
+        fee_mapper.set(ValidationFee {
+            payer: payer.clone(),
+            token_identifier: payment.token_identifier.clone(),
+            token_nonce: payment.token_nonce,
+            fee_per_validator: &payment.amount / validator_count as u64,
+            remaining: payment.amount.clone(),
+            deadline: self.blockchain().get_block_timestamp_seconds()
+                + self.validator_fee_timeout().get(),
+        });
+
+        self.validator_fee_escrowed_event(
+            request_hash,
+            payer,
+            ValidatorFeeEventData {
+                token_identifier: payment.token_identifier.clone(),
+                token_nonce: payment.token_nonce,
+                amount: payment.amount.clone(),
+            },
+        );
+    }
+
+    /// Pay `validator` its share of the escrowed fee, if one is due and the timeout has not passed.
+    /// Must only be called on the validator's first response.
+    fn pay_validator_fee(&self, request_hash: &ManagedBuffer, validator: &ManagedAddress) {
+        let fee_mapper = self.validation_fee(request_hash);
+        if fee_mapper.is_empty() {
+            return;
+        }
+
+        let mut fee = fee_mapper.get();
+        if self.blockchain().get_block_timestamp_seconds() > fee.deadline
+            || fee.fee_per_validator == 0u64
+            || fee.remaining < fee.fee_per_validator
+        {
+            return;
+        }
+
+        // Effects before interactions
+        fee.remaining -= &fee.fee_per_validator;
+        if fee.remaining == 0u64 {
+            fee_mapper.clear();
+        } else {
+            fee_mapper.set(&fee);
+        }
+
+        self.tx()
+            .to(validator)
+            .egld_or_single_esdt(
+                &fee.token_identifier,
+                fee.token_nonce,
+                &fee.fee_per_validator,
+            )
+            .transfer();
+
+        self.validator_fee_paid_event(
+            request_hash,
+            validator,
+            ValidatorFeeEventData {
+                token_identifier: fee.token_identifier,
+                token_nonce: fee.token_nonce,
+                amount: fee.fee_per_validator,
+            },
+        );
+    }
+
+    /// Request that exists, has not expired and is still short of its quorum.
+    fn require_open_request(
+        &self,
+        request_hash: &ManagedBuffer,
+    ) -> ValidationRequestData<Self::Api> {
+        let request_mapper = self.validation_request_data(request_hash);
+        require!(!request_mapper.is_empty(), ERR_VALIDATION_REQUEST_NOT_FOUND);
+        require!(
+            !self.request_expired(request_hash).get(),
+            ERR_REQUEST_EXPIRED
+        );
+
+        let request_data = request_mapper.get();
+        require!(
+            !self.is_quorum_reached(request_hash, &request_data),
+            ERR_QUORUM_ALREADY_REACHED
+        );
+        request_data
+    }
+
+    /// Requests opened before quorums only record their single response in `last_update`.
+    fn is_quorum_reached(
+        &self,
+        request_hash: &ManagedBuffer,
+        request_data: &ValidationRequestData<Self::Api>,
+    ) -> bool {
+        if self.request_validators(request_hash).is_empty() {
+            return request_data.last_update != TimestampSeconds::new(0);
+        }
+        self.validator_responses(request_hash).len()
+            >= self.request_quorum_or_default(request_hash) as usize
+    }
+
+    /// Whether `validator` may respond to the request. Requests opened before quorums
+    /// only accept their single `validator_address`.
+    fn is_nominated_validator(
+        &self,
+        request_hash: &ManagedBuffer,
+        request_data: &ValidationRequestData<Self::Api>,
+        validator: &ManagedAddress,
+    ) -> bool {
+        let nominated = self.request_validators(request_hash);
+        if nominated.is_empty() {
+            validator == &request_data.validator_address
+        } else {
+            nominated.contains(validator)
+        }
+    }
+
+    fn request_quorum_or_default(&self, request_hash: &ManagedBuffer) -> u32 {
+        let quorum_mapper = self.request_quorum(request_hash);
+        if quorum_mapper.is_empty() {
+            1
+        } else {
+            quorum_mapper.get()
+        }
+    }
+
+    fn aggregate_response(&self, request_hash: &ManagedBuffer) -> u8 {
+        median_score(
+            self.validator_responses(request_hash).values().map(
+                |validator_response: ValidatorResponse<Self::Api>| validator_response.response,
+            ),
+        )
+    }
+
+    /// Record a proof and move the job to `Pending`. Proofs are frozen once a validation
+    /// request is open, so validators always judge the proof that was submitted to them.
+    /// (`ValidationRequested -> Pending` is reserved for expiring unanswered requests.)
+    fn store_proof(&self, job_id: &ManagedBuffer, proof: ManagedBuffer) {
+        let job_mapper = self.job_data(job_id);
+        let mut job = job_mapper.get();
+        require!(
+            job.status != JobStatus::ValidationRequested,
+            ERR_PROOF_LOCKED
+        );
+        self.change_job_status(job_id, &mut job, JobStatus::Pending);
+        job.proof = proof;
+        job_mapper.set(&job);
+        self.job_proof_nonce(job_id).update(|nonce| *nonce += 1);
+    }
+
+    /// Score a response needs for `job_id` to be verified: the global threshold, raised by
+    /// the agent's per-service threshold when the job was opened for a service.
+    fn job_pass_threshold(&self, job_id: &ManagedBuffer, agent_nonce: u64) -> u8 {
+        let global = self.pass_threshold().get();
+        let service_mapper = self.job_service_id(job_id);
+        if service_mapper.is_empty() {
+            return global;
+        }
+
+        let identity_addr = self.identity_registry_address().get();
+        let service_threshold = self
+            .external_agent_service_thresholds(identity_addr, agent_nonce)
+            .get(&service_mapper.get())
+            .unwrap_or_default();
+        core::cmp::max(global, service_threshold)
+    }
+
+    /// Message signed by the agent key for `submit_signed_proof`:
+    /// `contract address || u64 big-endian proof nonce || u32 big-endian len(job_id) || job_id || proof`.
+    /// The address and the job's current proof nonce make each signature single-use on this
+    /// contract; the length prefix keeps (job_id, proof) pairs from colliding when concatenated.
+    fn signed_proof_message(&self, job_id: &ManagedBuffer, proof: &ManagedBuffer) -> ManagedBuffer {
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append_bytes(&self.job_proof_nonce(job_id).get().to_be_bytes());
+        message.append_u32_be(job_id.len() as u32);
+        message.append(job_id);
+        message.append(proof);
+        message
+    }
+
+    /// Every payment must be in a token the service accepts, and the payments in at
+    /// least one accepted token must add up to that token's price.
+    fn require_service_payment(
+        &self,
+        accepted: &ManagedVec<Payment<Self::Api>>,
+        payments: &ManagedVec<Payment<Self::Api>>,
+    ) {
+        for pay in payments.iter() {
+            require!(
+                accepted
+                    .iter()
+                    .any(|price| price.token_identifier == pay.token_identifier
+                        && price.token_nonce == pay.token_nonce),
+                ERR_INVALID_PAYMENT
+            );
+        }
+
+        let covered = accepted.iter().any(|price| {
+            let mut total = BigUint::zero();
+            for pay in payments.iter() {
+                if pay.token_identifier == price.token_identifier
+                    && pay.token_nonce == price.token_nonce
+                {
+                    total += pay.amount.as_big_uint();
+                }
+            }
+            total >= *price.amount.as_big_uint()
+        });
+        require!(covered, ERR_INSUFFICIENT_PAYMENT);
+    }
+
+    /// Pay out a held service payment once `job` is decided: to the agent owner if it was
+    /// verified, back to the employer otherwise (or if the agent no longer exists).
+    fn settle_held_payment(&self, job_id: &ManagedBuffer, job: &JobData<Self::Api>) {
+        let held_mapper = self.job_held_payment(job_id);
+        if held_mapper.is_empty() {
+            return;
+        }
+
+        let held = held_mapper.take();
+        let identity_addr = self.identity_registry_address().get();
+        let owner_mapper = self.external_agent_owner(identity_addr, job.agent_nonce);
+        if job.status == JobStatus::Verified && !owner_mapper.is_empty() {
+            let agent_owner = owner_mapper.get();
+            let net_payments = self.take_protocol_fees(&held.payments, held.protocol_fee_bps);
+            self.tx().to(&agent_owner).payment(&net_payments).transfer();
+            self.held_payment_released_event(
+                job_id,
+                &agent_owner,
+                HeldPaymentEventData {
+                    agent_nonce: job.agent_nonce,
+                    payments: net_payments,
+                    deadline: held.deadline,
+                },
+            );
+        } else {
+            self.tx()
+                .to(&job.employer)
+                .payment(&held.payments)
+                .transfer();
+            self.held_payment_refunded_event(
+                job_id,
+                &job.employer,
+                HeldPaymentEventData {
+                    agent_nonce: job.agent_nonce,
+                    payments: held.payments,
+                    deadline: held.deadline,
+                },
+            );
+        }
+    }
+
+    /// Whether the escrow contract still holds funds deposited for `job_id`.
+    fn has_active_escrow(&self, job_id: &ManagedBuffer) -> bool {
+        let escrow_addr_mapper = self.escrow_contract_address();
+        if escrow_addr_mapper.is_empty() {
+            return false;
+        }
+
+        let escrow_mapper = self.external_escrow_data(escrow_addr_mapper.get(), job_id);
+        !escrow_mapper.is_empty()
+            && matches!(
+                escrow_mapper.get().status,
+                EscrowStatus::Active | EscrowStatus::Disputed
+            )
+    }
+
+    /// Re-encode an agent's validation records written before URIs were stored,
+    /// leaving both URIs empty. Records already in the current layout are left as they are.
+    fn migrate_validation_requests(&self, agent_nonce: u64) {
+        for request_hash in self.agent_validations(agent_nonce).iter() {
+            let raw = self.raw_validation_request_data(&request_hash).get();
+            if ValidationRequestData::<Self::Api>::top_decode(raw.clone()).is_ok() {
+                continue;
+            }
+            let Ok(legacy) = LegacyValidationRequestData::<Self::Api>::top_decode(raw) else {
+                continue;
+            };
+
+            self.validation_request_data(&request_hash)
+                .set(ValidationRequestData {
+                    validator_address: legacy.validator_address,
+                    agent_nonce: legacy.agent_nonce,
+                    job_id: legacy.job_id,
+                    response: legacy.response,
+                    response_hash: legacy.response_hash,
+                    tag: legacy.tag,
+                    last_update: legacy.last_update,
+                    request_uri: ManagedBuffer::new(),
+                    response_uri: ManagedBuffer::new(),
+                });
+        }
+    }
+}
@@ -0,0 +1,261 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::events::{ValidatorStakeEventData, ValidatorTagsEventData, ValidatorUnbondingEventData};
+use crate::structs::{ValidatorInfo, ValidatorStatus};
+
+/// Validator registry: bonded validators with declared tags, slashable by the contract owner.
+#[multiversx_sc::module]
+pub trait ValidatorsModule:
+    common::cross_contract::CrossContractModule
+    + crate::storage::ExternalStorageModule
+    + crate::events::EventsModule
+{
+    /// Configure validator bonding. Existing stakes keep the token they were bonded in.
+    #[only_owner]
+    #[endpoint(set_validator_staking)]
+    fn set_validator_staking(
+        &self,
+        stake_token: EgldOrEsdtTokenIdentifier,
+        min_stake: BigUint,
+        unbonding_period: DurationSeconds,
+    ) {
+        require!(stake_token.is_valid(), ERR_INVALID_STAKE_TOKEN);
+        self.validator_stake_token().set(&stake_token);
+        self.min_validator_stake().set(&min_stake);
+        self.validator_unbonding_period().set(unbonding_period);
+    }
+
+    /// Require every validator nominated in `validation_request` to be eligible
+    /// (active and staked at least the minimum).
+    #[only_owner]
+    #[endpoint(set_require_staked_validators)]
+    fn set_require_staked_validators(&self, required: bool) {
+        self.require_staked_validators().set(required);
+    }
+
+    /// Register the caller as a validator, bonding the attached payment as stake.
+    #[payable("*")]
+    #[endpoint(register_validator)]
+    fn register_validator(&self, tags: MultiValueEncoded<ManagedBuffer>) {
+        require!(
+            !self.validator_stake_token().is_empty(),
+            ERR_STAKING_NOT_CONFIGURED
+        );
+        let caller = self.blockchain().get_caller();
+        let info_mapper = self.validator_info(&caller);
+        require!(info_mapper.is_empty(), ERR_VALIDATOR_ALREADY_REGISTERED);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(
+            payment.token_identifier == self.validator_stake_token().get(),
+            ERR_INVALID_STAKE_TOKEN
+        );
+        require!(
+            payment.amount >= self.min_validator_stake().get(),
+            ERR_INSUFFICIENT_STAKE
+        );
+
+        let tags = tags.to_vec();
+        for tag in tags.iter() {
+            self.tag_validators(&tag).insert(caller.clone());
+        }
+        self.registered_validators().insert(caller.clone());
+        info_mapper.set(ValidatorInfo {
+            stake_token: payment.token_identifier.clone(),
+            stake_nonce: payment.token_nonce,
+            stake: payment.amount.clone(),
+            tags,
+            status: ValidatorStatus::Active,
+            unbond_timestamp: TimestampSeconds::new(0),
+        });
+
+        self.validator_registered_event(
+            &caller,
+            ValidatorStakeEventData {
+                token_identifier: payment.token_identifier.clone(),
+                token_nonce: payment.token_nonce,
+                amount: payment.amount.clone(),
+                total_stake: payment.amount.clone(),
+            },
+        );
+    }
+
+    /// Top up the caller's stake, in the token it was bonded in.
+    #[payable("*")]
+    #[endpoint(add_validator_stake)]
+    fn add_validator_stake(&self) {
+        let caller = self.blockchain().get_caller();
+        let info_mapper = self.validator_info(&caller);
+        require!(!info_mapper.is_empty(), ERR_VALIDATOR_NOT_REGISTERED);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        let mut info = info_mapper.get();
+        require!(
+            info.status == ValidatorStatus::Active,
+            ERR_VALIDATOR_NOT_ACTIVE
+        );
+        require!(
+            payment.token_identifier == info.stake_token && payment.token_nonce == info.stake_nonce,
+            ERR_INVALID_STAKE_TOKEN
+        );
+
+        info.stake += &payment.amount;
+        info_mapper.set(&info);
+
+        self.validator_stake_added_event(
+            &caller,
+            ValidatorStakeEventData {
+                token_identifier: info.stake_token,
+                token_nonce: info.stake_nonce,
+                amount: payment.amount.clone(),
+                total_stake: info.stake,
+            },
+        );
+    }
+
+    /// Replace the tags (validation domains) the caller declares to support.
+    #[endpoint(set_validator_tags)]
+    fn set_validator_tags(&self, tags: MultiValueEncoded<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+        let info_mapper = self.validator_info(&caller);
+        require!(!info_mapper.is_empty(), ERR_VALIDATOR_NOT_REGISTERED);
+
+        let mut info = info_mapper.get();
+        for tag in info.tags.iter() {
+            self.tag_validators(&tag).swap_remove(&caller);
+        }
+        info.tags = tags.to_vec();
+        for tag in info.tags.iter() {
+            self.tag_validators(&tag).insert(caller.clone());
+        }
+        info_mapper.set(&info);
+
+        self.validator_tags_updated_event(&caller, ValidatorTagsEventData { tags: info.tags });
+    }
+
+    /// Stop accepting nominations and start the unbonding period.
+    #[endpoint(unbond_validator)]
+    fn unbond_validator(&self) {
+        let caller = self.blockchain().get_caller();
+        let info_mapper = self.validator_info(&caller);
+        require!(!info_mapper.is_empty(), ERR_VALIDATOR_NOT_REGISTERED);
+
+        let mut info = info_mapper.get();
+        require!(
+            info.status == ValidatorStatus::Active,
+            ERR_VALIDATOR_NOT_ACTIVE
+        );
+        info.status = ValidatorStatus::Unbonding;
+        info.unbond_timestamp = self.blockchain().get_block_timestamp_seconds()
+            + self.validator_unbonding_period().get();
+        info_mapper.set(&info);
+
+        self.validator_unbonding_event(
+            &caller,
+            ValidatorUnbondingEventData {
+                unbond_timestamp: info.unbond_timestamp,
+                stake: info.stake,
+            },
+        );
+    }
+
+    /// Withdraw the remaining stake once unbonding is over. Removes the validator.
+    #[endpoint(withdraw_validator_stake)]
+    fn withdraw_validator_stake(&self) {
+        let caller = self.blockchain().get_caller();
+        let info_mapper = self.validator_info(&caller);
+        require!(!info_mapper.is_empty(), ERR_VALIDATOR_NOT_REGISTERED);
+
+        let info = info_mapper.get();
+        require!(
+            info.status == ValidatorStatus::Unbonding,
+            ERR_VALIDATOR_NOT_UNBONDING
+        );
+        require!(
+            self.blockchain().get_block_timestamp_seconds() >= info.unbond_timestamp,
+            ERR_UNBONDING_NOT_FINISHED
+        );
+
+        // Effects before interactions
+        for tag in info.tags.iter() {
+            self.tag_validators(&tag).swap_remove(&caller);
+        }
+        self.registered_validators().swap_remove(&caller);
+        info_mapper.clear();
+
+        if info.stake > 0u64 {
+            self.tx()
+                .to(&caller)
+                .egld_or_single_esdt(&info.stake_token, info.stake_nonce, &info.stake)
+                .transfer();
+        }
+
+        self.validator_withdrawn_event(
+            &caller,
+            ValidatorStakeEventData {
+                token_identifier: info.stake_token,
+                token_nonce: info.stake_nonce,
+                amount: info.stake,
+                total_stake: BigUint::zero(),
+            },
+        );
+    }
+
+    /// Governance: slash `amount` of a validator's stake for its response to `request_hash`.
+    /// Slashed funds go to the contract owner.
+    #[only_owner]
+    #[endpoint(slash_validator)]
+    fn slash_validator(
+        &self,
+        validator: ManagedAddress,
+        request_hash: ManagedBuffer,
+        amount: BigUint,
+    ) {
+        let info_mapper = self.validator_info(&validator);
+        require!(!info_mapper.is_empty(), ERR_VALIDATOR_NOT_REGISTERED);
+        require!(
+            self.validator_responses(&request_hash)
+                .contains_key(&validator),
+            ERR_NO_RESPONSE_TO_SLASH
+        );
+
+        let mut info = info_mapper.get();
+        require!(
+            amount > 0u64 && amount <= info.stake,
+            ERR_INVALID_SLASH_AMOUNT
+        );
+        info.stake -= &amount;
+        info_mapper.set(&info);
+
+        let owner = self.blockchain().get_caller();
+        self.tx()
+            .to(&owner)
+            .egld_or_single_esdt(&info.stake_token, info.stake_nonce, &amount)
+            .transfer();
+
+        self.validator_slashed_event(
+            &validator,
+            &request_hash,
+            ValidatorStakeEventData {
+                token_identifier: info.stake_token,
+                token_nonce: info.stake_nonce,
+                amount,
+                total_stake: info.stake,
+            },
+        );
+    }
+
+    /// Active and staked at least the current minimum, in the current stake token.
+    fn is_eligible_validator(&self, validator: &ManagedAddress) -> bool {
+        let info_mapper = self.validator_info(validator);
+        if info_mapper.is_empty() {
+            return false;
+        }
+
+        let info = info_mapper.get();
+        info.status == ValidatorStatus::Active
+            && info.stake_token == self.validator_stake_token().get()
+            && info.stake >= self.min_validator_stake().get()
+    }
+}
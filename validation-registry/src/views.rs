@@ -1,11 +1,19 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::structs::{JobData, ValidationRequestData};
+use crate::structs::{
+    HeldPayment, JobData, ValidationFee, ValidationOutcome, ValidationRequestData, ValidatorInfo,
+    ValidatorResponse,
+};
 
 #[multiversx_sc::module]
 pub trait ViewsModule:
-    common::cross_contract::CrossContractModule + crate::storage::ExternalStorageModule
+    common::cross_contract::CrossContractModule
+    + crate::storage::ExternalStorageModule
+    + crate::utils::UtilsModule
+    + crate::events::EventsModule
+    + crate::validators::ValidatorsModule
+    + common::fees::FeesModule
 {
     #[view(is_job_verified)]
     fn is_job_verified(&self, job_id: ManagedBuffer) -> bool {
@@ -23,6 +31,17 @@ pub trait ViewsModule:
         }
     }
 
+    /// Service payment held for a pay-on-verification job, if not settled yet.
+    #[view(get_held_payment)]
+    fn get_held_payment(&self, job_id: ManagedBuffer) -> OptionalValue<HeldPayment<Self::Api>> {
+        let held_mapper = self.job_held_payment(&job_id);
+        if held_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(held_mapper.get())
+        }
+    }
+
     /// ERC-8004: Returns validation status for a request hash.
     #[view(get_validation_status)]
     fn get_validation_status(
@@ -46,4 +65,116 @@ pub trait ViewsModule:
         }
         result
     }
+
+    /// Request hashes opened on a job, oldest first.
+    #[view(get_job_validation_history)]
+    fn get_job_validation_history(
+        &self,
+        job_id: ManagedBuffer,
+    ) -> MultiValueEncoded<ManagedBuffer> {
+        let mut result = MultiValueEncoded::new();
+        for hash in self.job_validation_history(&job_id).iter() {
+            result.push(hash);
+        }
+        result
+    }
+
+    /// Validators nominated for a request.
+    #[view(get_request_validators)]
+    fn get_request_validators(
+        &self,
+        request_hash: ManagedBuffer,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        let nominated = self.request_validators(&request_hash);
+        if nominated.is_empty() {
+            let request_mapper = self.validation_request_data(&request_hash);
+            if !request_mapper.is_empty() {
+                result.push(request_mapper.get().validator_address);
+            }
+        } else {
+            for validator in nominated.iter() {
+                result.push(validator);
+            }
+        }
+        result
+    }
+
+    /// Latest response of one validator to a request.
+    #[view(get_validator_response)]
+    fn get_validator_response(
+        &self,
+        request_hash: ManagedBuffer,
+        validator: ManagedAddress,
+    ) -> OptionalValue<ValidatorResponse<Self::Api>> {
+        if let Some(response) = self.validator_responses(&request_hash).get(&validator) {
+            OptionalValue::Some(response)
+        } else {
+            OptionalValue::None
+        }
+    }
+
+    /// Quorum progress and median response of a request.
+    #[view(get_validation_outcome)]
+    fn get_validation_outcome(
+        &self,
+        request_hash: ManagedBuffer,
+    ) -> OptionalValue<ValidationOutcome> {
+        let request_mapper = self.validation_request_data(&request_hash);
+        if request_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let request_data = request_mapper.get();
+        OptionalValue::Some(ValidationOutcome {
+            quorum: self.request_quorum_or_default(&request_hash),
+            validator_count: self.request_validators(&request_hash).len().max(1) as u32,
+            response_count: self.validator_responses(&request_hash).len() as u32,
+            aggregate_response: self.aggregate_response(&request_hash),
+            quorum_reached: self.is_quorum_reached(&request_hash, &request_data),
+            deadline: self.request_deadline(&request_hash).get(),
+            expired: self.request_expired(&request_hash).get(),
+        })
+    }
+
+    /// Validator fee still escrowed for a request (absent once fully paid out or refunded).
+    #[view(get_validation_fee)]
+    fn get_validation_fee(
+        &self,
+        request_hash: ManagedBuffer,
+    ) -> OptionalValue<ValidationFee<Self::Api>> {
+        let mapper = self.validation_fee(&request_hash);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(get_validator)]
+    fn get_validator(&self, validator: ManagedAddress) -> OptionalValue<ValidatorInfo<Self::Api>> {
+        let mapper = self.validator_info(&validator);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    /// Whether `validator` is active and staked at least the current minimum.
+    #[view(is_validator_eligible)]
+    fn is_validator_eligible(&self, validator: ManagedAddress) -> bool {
+        self.is_eligible_validator(&validator)
+    }
+
+    #[view(get_registered_validators)]
+    fn get_registered_validators(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.registered_validators().iter().collect()
+    }
+
+    /// Registered validators declaring support for `tag`.
+    #[view(get_validators_by_tag)]
+    fn get_validators_by_tag(&self, tag: ManagedBuffer) -> MultiValueEncoded<ManagedAddress> {
+        self.tag_validators(&tag).iter().collect()
+    }
 }
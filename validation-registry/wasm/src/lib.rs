@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
+// Endpoints:                           61
 // Async Callback (empty):               1
-// Total number of exported functions:  14
+// Total number of exported functions:  64
 
 #![no_std]
 
@@ -21,16 +21,66 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         init_job => init_job
+        cancel_job => cancel_job
         submit_proof => submit_proof
-        submit_proof_with_nft => submit_proof_with_nft
+        submit_signed_proof => submit_signed_proof
+        enable_job_milestones => enable_job_milestones
         validation_request => validation_request
+        quorum_validation_request => quorum_validation_request
         validation_response => validation_response
+        expire_validation_request => expire_validation_request
+        renominate_validator => renominate_validator
+        refund_validation_fee => refund_validation_fee
+        refund_held_payment => refund_held_payment
         clean_old_jobs => clean_old_jobs
+        is_milestone_job => job_milestones
+        get_proof_nonce => job_proof_nonce
+        get_payment_hold_timeout => payment_hold_timeout
+        get_escrow_contract_address => escrow_contract_address
+        get_pass_threshold => pass_threshold
+        is_request_passed => request_passed
+        get_validation_request_timeout => validation_request_timeout
+        get_expiry_grace_period => expiry_grace_period
+        get_validator_fee_timeout => validator_fee_timeout
+        get_validator_stake_token => validator_stake_token
+        get_min_validator_stake => min_validator_stake
+        get_validator_unbonding_period => validator_unbonding_period
+        is_staked_validator_required => require_staked_validators
         is_job_verified => is_job_verified
         get_job_data => get_job_data
+        get_held_payment => get_held_payment
         get_validation_status => get_validation_status
         get_agent_validations => get_agent_validations
+        get_job_validation_history => get_job_validation_history
+        get_request_validators => get_request_validators
+        get_validator_response => get_validator_response
+        get_validation_outcome => get_validation_outcome
+        get_validation_fee => get_validation_fee
+        get_validator => get_validator
+        is_validator_eligible => is_validator_eligible
+        get_registered_validators => get_registered_validators
+        get_validators_by_tag => get_validators_by_tag
         set_identity_registry_address => set_identity_registry_address
+        set_escrow_contract_address => set_escrow_contract_address
+        set_validation_request_timeout => set_validation_request_timeout
+        set_expiry_grace_period => set_expiry_grace_period
+        set_validator_fee_timeout => set_validator_fee_timeout
+        set_payment_hold_timeout => set_payment_hold_timeout
+        set_pass_threshold => set_pass_threshold
+        set_validator_staking => set_validator_staking
+        set_require_staked_validators => set_require_staked_validators
+        register_validator => register_validator
+        add_validator_stake => add_validator_stake
+        set_validator_tags => set_validator_tags
+        unbond_validator => unbond_validator
+        withdraw_validator_stake => withdraw_validator_stake
+        slash_validator => slash_validator
+        set_protocol_fee => set_protocol_fee
+        set_treasury => set_treasury
+        claim_fees => claim_fees
+        get_accumulated_fees => get_accumulated_fees
+        get_protocol_fee_bps => protocol_fee_bps
+        get_treasury => treasury
     )
 }
 